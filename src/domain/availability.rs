@@ -0,0 +1,105 @@
+use super::weekday::Weekday;
+use chrono::{NaiveDate, NaiveTime, Timelike};
+
+pub const DATE_FORMAT: &str = "%Y-%m-%d";
+pub const TIME_FORMAT: &str = "%H:%M";
+const MINUTES_PER_DAY: i64 = 24 * 60;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TimeWindow {
+  pub start: NaiveTime,
+  pub end: NaiveTime,
+}
+
+impl TimeWindow {
+  fn span(&self) -> (i64, i64) {
+    let start = minute_of_day(self.start);
+    let mut end = minute_of_day(self.end);
+    if end <= start {
+      end += MINUTES_PER_DAY;
+    }
+    (start, end)
+  }
+
+  pub fn duration_minutes(&self) -> i64 {
+    let (start, end) = self.span();
+    end - start
+  }
+}
+
+fn minute_of_day(time: NaiveTime) -> i64 {
+  (time.num_seconds_from_midnight() / 60) as i64
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DateRange {
+  pub start: NaiveDate,
+  pub end: NaiveDate,
+}
+
+impl DateRange {
+  pub fn contains(&self, date: NaiveDate) -> bool {
+    self.start <= date && date <= self.end
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DayAvailability {
+  pub day: Weekday,
+  pub window: Option<TimeWindow>,
+}
+
+impl DayAvailability {
+  fn span(&self) -> (i64, i64) {
+    self.window.map_or((0, MINUTES_PER_DAY), |w| w.span())
+  }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Availability {
+  days: Vec<DayAvailability>,
+}
+
+impl Availability {
+  pub fn new(days: Vec<DayAvailability>) -> Availability {
+    let mut availability = Availability { days: Vec::new() };
+    for day in days {
+      if !availability.days.contains(&day) {
+        availability.days.push(day);
+      }
+    }
+    availability
+  }
+
+  pub fn is_available_on(&self, day: Weekday) -> bool {
+    self.days.iter().any(|d| d.day == day)
+  }
+
+  pub fn overlap_minutes(&self, day: Weekday, shift: &TimeWindow) -> i64 {
+    let (shift_start, shift_end) = shift.span();
+    let mut intervals: Vec<(i64, i64)> = Vec::new();
+    for offset in -1..=1 {
+      let offset_day = day.plus_days(offset);
+      for entry in self.days.iter().filter(|d| d.day == offset_day) {
+        let (start, end) = entry.span();
+        let start = (start + offset * MINUTES_PER_DAY).max(shift_start);
+        let end = (end + offset * MINUTES_PER_DAY).min(shift_end);
+        if start < end {
+          intervals.push((start, end));
+        }
+      }
+    }
+    intervals.sort_unstable();
+
+    let mut overlap = 0;
+    let mut covered_until = shift_start;
+    for (start, end) in intervals {
+      let start = start.max(covered_until);
+      if end > start {
+        overlap += end - start;
+        covered_until = end;
+      }
+    }
+    overlap
+  }
+}
@@ -0,0 +1,33 @@
+use std::collections::BTreeSet;
+use std::iter::FromIterator;
+
+#[derive(Clone, Debug, Default)]
+pub struct CertificateSet {
+  certificates: BTreeSet<String>,
+}
+
+impl CertificateSet {
+  pub fn contains(&self, certificate: &str) -> bool {
+    self.certificates.contains(certificate)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.certificates.is_empty()
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = &String> {
+    self.certificates.iter()
+  }
+}
+
+impl FromIterator<String> for CertificateSet {
+  fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> CertificateSet {
+    CertificateSet {
+      certificates: iter
+        .into_iter()
+        .map(|c| String::from(c.trim()))
+        .filter(|c| !c.is_empty())
+        .collect(),
+    }
+  }
+}
@@ -1,12 +1,132 @@
 use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
 
 #[derive(Deserialize)]
-pub struct RatingWeights {
-  pub available_on_start_days: f64,
-  pub required_certificates: f64,
-  pub job_location: f64,
-  pub pay_rate: f64,
-  pub job_positions: f64,
+pub struct RuleConfig {
+  pub name: String,
+  pub weight: f64,
+  #[serde(default)]
+  pub params: HashMap<String, Value>,
+  pub filter: Option<String>,
+  pub score: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct HttpConfig {
+  pub timeout_ms: u64,
+  pub max_concurrent_requests: usize,
+  pub max_retries: u32,
+  pub initial_backoff_ms: u64,
+  pub max_backoff_ms: u64,
+  pub circuit_breaker_threshold: u32,
+  pub circuit_breaker_reset_ms: u64,
+  pub lookup_index_max_age_ms: u64,
+}
+
+impl Default for HttpConfig {
+  fn default() -> HttpConfig {
+    HttpConfig {
+      timeout_ms: 10000,
+      max_concurrent_requests: 4,
+      max_retries: 3,
+      initial_backoff_ms: 200,
+      max_backoff_ms: 5000,
+      circuit_breaker_threshold: 5,
+      circuit_breaker_reset_ms: 30000,
+      lookup_index_max_age_ms: 30000,
+    }
+  }
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RepositoryConfig {
+  Rest {
+    base_url: String,
+    #[serde(default)]
+    http: HttpConfig,
+  },
+  File {
+    directory: String,
+    watch_interval_seconds: Option<u64>,
+  },
+  Sqlite {
+    path: String,
+    upstream_url: String,
+    #[serde(default)]
+    http: HttpConfig,
+  },
+}
+
+#[derive(Deserialize)]
+pub struct CacheConfig {
+  pub ttl_seconds: u64,
+  #[serde(default = "default_max_staleness_seconds")]
+  pub max_staleness_seconds: u64,
+  pub refresh_interval_seconds: u64,
+}
+
+fn default_max_staleness_seconds() -> u64 {
+  3600
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DistanceEvaluatorType {
+  #[default]
+  Pythagoras,
+  Haversine,
+  Vincenty,
+}
+
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct TravelConfig {
+  pub speeds_kmh: HashMap<String, f64>,
+  pub default_speed_kmh: f64,
+  pub reference_commute_minutes: f64,
+}
+
+impl Default for TravelConfig {
+  fn default() -> TravelConfig {
+    let speeds_kmh = vec![
+      ("car", 40.0),
+      ("public_transport", 20.0),
+      ("bike", 15.0),
+      ("walk", 5.0),
+    ];
+    TravelConfig {
+      speeds_kmh: speeds_kmh
+        .into_iter()
+        .map(|(mode, speed)| (String::from(mode), speed))
+        .collect(),
+      default_speed_kmh: 20.0,
+      reference_commute_minutes: 60.0,
+    }
+  }
+}
+
+#[derive(Deserialize)]
+pub struct TimezoneRegionConfig {
+  pub timezone: String,
+  pub min_latitude: f64,
+  pub max_latitude: f64,
+  pub min_longitude: f64,
+  pub max_longitude: f64,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+pub struct TimezoneConfig {
+  pub default: Option<String>,
+  pub regions: Vec<TimezoneRegionConfig>,
+}
+
+#[derive(Deserialize)]
+pub struct WebhookConfig {
+  pub token: String,
 }
 
 #[derive(Deserialize)]
@@ -14,6 +134,16 @@ pub struct Config {
   pub app_name: String,
   pub jobs_to_return: u32,
   pub workers_to_return: u32,
-  pub base_url: String,
-  pub weights: RatingWeights,
+  pub repository: RepositoryConfig,
+  pub rules: Vec<RuleConfig>,
+  #[serde(default)]
+  pub distance_evaluator: DistanceEvaluatorType,
+  #[serde(default)]
+  pub travel: TravelConfig,
+  #[serde(default)]
+  pub timezones: TimezoneConfig,
+  #[serde(default)]
+  pub company_minimum_ratings: HashMap<String, u32>,
+  pub cache: Option<CacheConfig>,
+  pub webhook: Option<WebhookConfig>,
 }
@@ -0,0 +1,75 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GeoPoint {
+  latitude: f64,
+  longitude: f64,
+}
+
+impl GeoPoint {
+  pub fn new(latitude: f64, longitude: f64) -> Result<GeoPoint, String> {
+    if !(-90.0..=90.0).contains(&latitude) {
+      return Err(format!("Latitude {} is out of range", latitude));
+    }
+    if !(-180.0..=180.0).contains(&longitude) {
+      return Err(format!("Longitude {} is out of range", longitude));
+    }
+
+    Ok(GeoPoint {
+      latitude,
+      longitude,
+    })
+  }
+
+  pub fn parse(latitude: &str, longitude: &str) -> Result<GeoPoint, String> {
+    let latitude = latitude
+      .parse::<f64>()
+      .map_err(|e| format!("Could not parse latitude '{}': {}", latitude, e))?;
+    let longitude = longitude
+      .parse::<f64>()
+      .map_err(|e| format!("Could not parse longitude '{}': {}", longitude, e))?;
+    GeoPoint::new(latitude, longitude)
+  }
+
+  pub fn latitude(&self) -> f64 {
+    self.latitude
+  }
+
+  pub fn longitude(&self) -> f64 {
+    self.longitude
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DistanceUnit {
+  Kilometres,
+  Miles,
+  Metres,
+}
+
+impl DistanceUnit {
+  pub fn parse(unit: &str) -> Result<DistanceUnit, String> {
+    match unit.trim().to_lowercase().as_str() {
+      "km" | "kilometre" | "kilometres" | "kilometer" | "kilometers" => {
+        Ok(DistanceUnit::Kilometres)
+      }
+      "mi" | "mile" | "miles" => Ok(DistanceUnit::Miles),
+      "m" | "metre" | "metres" | "meter" | "meters" => Ok(DistanceUnit::Metres),
+      _ => Err(format!("Unknown distance unit '{}'", unit)),
+    }
+  }
+
+  fn km_per_unit(&self) -> f64 {
+    match self {
+      DistanceUnit::Kilometres => 1.0,
+      DistanceUnit::Miles => 1.609344,
+      DistanceUnit::Metres => 0.001,
+    }
+  }
+
+  pub fn convert_to_km(&self, distance: f64) -> f64 {
+    distance * self.km_per_unit()
+  }
+
+  pub fn convert_from_km(&self, distance: f64) -> f64 {
+    distance / self.km_per_unit()
+  }
+}
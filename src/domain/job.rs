@@ -0,0 +1,112 @@
+use super::certificates::CertificateSet;
+use super::geo::GeoPoint;
+use super::money::Money;
+use super::schedule::JobSchedule;
+use super::skills::{self, SkillSet};
+use crate::dto::JobDto;
+use chrono::{DateTime, FixedOffset};
+use chrono_tz::Tz;
+use std::convert::TryFrom;
+
+#[derive(Clone)]
+pub struct Job {
+  pub job_id: u32,
+  pub guid: String,
+  pub location: Option<GeoPoint>,
+  pub bill_rate: Option<Money>,
+  pub workers_required: u32,
+  pub driver_license_required: bool,
+  pub required_certificates: CertificateSet,
+  pub start_date: DateTime<FixedOffset>,
+  pub about: String,
+  pub about_terms: String,
+  pub company: String,
+  pub required_skills: SkillSet,
+  pub preferred_skills: SkillSet,
+  pub minimum_rating: Option<u32>,
+  pub timezone: Option<Tz>,
+  pub schedule: Option<JobSchedule>,
+  /// The record as received, returned unchanged by the API and stored as is.
+  pub source: JobDto,
+}
+
+impl Job {
+  /// Converts an upstream record. An unparseable location or bill rate is
+  /// returned as a warning next to the job and left unset; any other problem
+  /// rejects the record.
+  pub fn parse(dto: JobDto) -> Result<(Job, Vec<String>), Vec<String>> {
+    let source = dto.clone();
+    let mut warnings = Vec::new();
+    let location = GeoPoint::parse(&dto.location.latitude, &dto.location.longitude)
+      .map_err(|e| warnings.push(format!("location: {}", e)))
+      .ok();
+    let bill_rate = Money::parse(&dto.bill_rate)
+      .map_err(|e| warnings.push(format!("billRate: {}", e)))
+      .ok();
+    let timezone = dto
+      .timezone
+      .as_ref()
+      .map(|t| {
+        t.parse::<Tz>()
+          .map_err(|_| format!("timezone: unknown timezone '{}'", t))
+      })
+      .transpose();
+    let schedule = dto.schedule.map(JobSchedule::try_from).transpose();
+    match (timezone, schedule) {
+      (Ok(timezone), Ok(schedule)) => Ok((
+        Job {
+          job_id: dto.job_id,
+          guid: dto.guid,
+          location,
+          bill_rate,
+          workers_required: dto.workers_required,
+          driver_license_required: dto.driver_license_required,
+          required_certificates: dto.required_certificates.into_iter().collect(),
+          start_date: dto.start_date,
+          about_terms: skills::normalize(&dto.about),
+          about: dto.about,
+          company: dto.company,
+          required_skills: dto
+            .required_skills
+            .unwrap_or_default()
+            .into_iter()
+            .collect(),
+          preferred_skills: dto
+            .preferred_skills
+            .unwrap_or_default()
+            .into_iter()
+            .collect(),
+          minimum_rating: dto.minimum_rating,
+          timezone,
+          schedule,
+          source,
+        },
+        warnings,
+      )),
+      (timezone, schedule) => Err(
+        timezone
+          .err()
+          .into_iter()
+          .chain(schedule.err().into_iter().flatten())
+          .collect(),
+      ),
+    }
+  }
+}
+
+impl TryFrom<JobDto> for Job {
+  type Error = Vec<String>;
+
+  fn try_from(dto: JobDto) -> Result<Job, Vec<String>> {
+    match Job::parse(dto)? {
+      (job, warnings) if warnings.is_empty() => Ok(job),
+      (_, warnings) => Err(warnings),
+    }
+  }
+}
+
+impl From<&Job> for JobDto {
+  fn from(job: &Job) -> JobDto {
+    job.source.clone()
+  }
+}
@@ -1 +1,15 @@
+pub mod availability;
+pub mod certificates;
 pub mod config;
+pub mod geo;
+pub mod job;
+pub mod money;
+pub mod schedule;
+pub mod skills;
+pub mod timezone;
+pub mod transport;
+pub mod weekday;
+pub mod worker;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,33 @@
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Money {
+  cents: i64,
+}
+
+impl Money {
+  pub fn parse(value: &str) -> Result<Money, String> {
+    let amount = value
+      .trim()
+      .trim_start_matches('$')
+      .parse::<f64>()
+      .map_err(|e| format!("Could not parse amount '{}': {}", value, e))?;
+    if !amount.is_finite() || amount < 0.0 {
+      return Err(format!("Amount '{}' must be a positive number", value));
+    }
+
+    Ok(Money {
+      cents: (amount * 100.0).round() as i64,
+    })
+  }
+
+  pub fn amount(&self) -> f64 {
+    self.cents as f64 / 100.0
+  }
+}
+
+impl fmt::Display for Money {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "${}.{:02}", self.cents / 100, self.cents % 100)
+  }
+}
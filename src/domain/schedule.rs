@@ -0,0 +1,115 @@
+use super::availability::{TimeWindow, DATE_FORMAT, TIME_FORMAT};
+use super::weekday::Weekday;
+use crate::dto::JobScheduleDto;
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime};
+use std::convert::TryFrom;
+
+const MAX_SCHEDULE_DAYS: i64 = 366;
+
+#[derive(Clone, Debug)]
+pub struct JobSchedule {
+  pub start_date: NaiveDate,
+  pub end_date: NaiveDate,
+  pub weekdays: Vec<Weekday>,
+  pub shift: Option<TimeWindow>,
+  pub minimum_coverage: Option<f64>,
+}
+
+impl JobSchedule {
+  pub fn working_days(&self) -> Vec<NaiveDate> {
+    let days = (self.end_date - self.start_date).num_days();
+    (0..=days)
+      .map(|d| self.start_date + Duration::days(d))
+      .filter(|d| self.weekdays.is_empty() || self.weekdays.contains(&Weekday::from(d.weekday())))
+      .collect()
+  }
+}
+
+fn parse_date(field: &str, value: &str) -> Result<NaiveDate, String> {
+  NaiveDate::parse_from_str(value, DATE_FORMAT)
+    .map_err(|_| format!("schedule.{}: expected YYYY-MM-DD, got '{}'", field, value))
+}
+
+fn parse_time(field: &str, value: &str) -> Result<NaiveTime, String> {
+  NaiveTime::parse_from_str(value, TIME_FORMAT)
+    .map_err(|_| format!("schedule.{}: expected HH:MM, got '{}'", field, value))
+}
+
+impl TryFrom<JobScheduleDto> for JobSchedule {
+  type Error = Vec<String>;
+
+  fn try_from(dto: JobScheduleDto) -> Result<JobSchedule, Vec<String>> {
+    let mut errors: Vec<String> = Vec::new();
+    let start_date = parse_date("startDate", &dto.start_date).map_err(|e| errors.push(e));
+    let end_date = parse_date("endDate", &dto.end_date).map_err(|e| errors.push(e));
+    let mut weekdays: Vec<Weekday> = Vec::new();
+    for day_index in &dto.weekdays {
+      match Weekday::from_day_index(*day_index) {
+        Some(weekday) => weekdays.push(weekday),
+        None => errors.push(format!(
+          "schedule.weekdays: invalid day index {}",
+          day_index
+        )),
+      }
+    }
+    let shift = match (&dto.shift_start, &dto.shift_end) {
+      (Some(start), Some(end)) => {
+        match (parse_time("shiftStart", start), parse_time("shiftEnd", end)) {
+          (Ok(start), Ok(end)) => Some(TimeWindow { start, end }),
+          (start, end) => {
+            errors.extend(start.err());
+            errors.extend(end.err());
+            None
+          }
+        }
+      }
+      (None, None) => None,
+      _ => {
+        errors.push(String::from(
+          "schedule: shiftStart and shiftEnd must be given together",
+        ));
+        None
+      }
+    };
+    if let Some(minimum_coverage) = dto.minimum_coverage {
+      if !(0.0..=1.0).contains(&minimum_coverage) {
+        errors.push(format!(
+          "schedule.minimumCoverage: must be between 0 and 1, got {}",
+          minimum_coverage
+        ));
+      }
+    }
+
+    let (start_date, end_date) = match (start_date, end_date) {
+      (Ok(start_date), Ok(end_date)) => (start_date, end_date),
+      _ => return Err(errors),
+    };
+    let days = (end_date - start_date).num_days();
+    if days < 0 {
+      errors.push(String::from("schedule: endDate is before startDate"));
+    } else if days >= MAX_SCHEDULE_DAYS {
+      errors.push(format!(
+        "schedule: spans more than {} days",
+        MAX_SCHEDULE_DAYS
+      ));
+    }
+    if !errors.is_empty() {
+      return Err(errors);
+    }
+
+    let schedule = JobSchedule {
+      start_date,
+      end_date,
+      weekdays,
+      shift,
+      minimum_coverage: dto.minimum_coverage,
+    };
+    if schedule.working_days().is_empty() {
+      return Err(vec![String::from(
+        "schedule: no working days between startDate and endDate",
+      )]);
+    }
+
+    Ok(schedule)
+  }
+}
@@ -0,0 +1,69 @@
+use std::iter::FromIterator;
+
+pub fn normalize(text: &str) -> String {
+  text
+    .to_lowercase()
+    .split(|c: char| !c.is_alphanumeric())
+    .filter(|w| !w.is_empty())
+    .collect::<Vec<&str>>()
+    .join(" ")
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SkillSet {
+  skills: Vec<(String, String)>,
+}
+
+impl SkillSet {
+  pub fn len(&self) -> usize {
+    self.skills.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.skills.is_empty()
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = &String> {
+    self.skills.iter().map(|s| &s.0)
+  }
+
+  pub fn contains(&self, normalized: &str) -> bool {
+    self.skills.iter().any(|s| s.1 == normalized)
+  }
+
+  pub fn count_found_in(&self, other: &SkillSet) -> usize {
+    self.skills.iter().filter(|s| other.contains(&s.1)).count()
+  }
+
+  pub fn missing_from(&self, other: &SkillSet) -> Vec<String> {
+    self
+      .skills
+      .iter()
+      .filter(|s| !other.contains(&s.1))
+      .map(|s| s.0.clone())
+      .collect()
+  }
+
+  pub fn count_mentioned_in(&self, normalized_text: &str) -> usize {
+    let text = format!(" {} ", normalized_text);
+    self
+      .skills
+      .iter()
+      .filter(|s| text.contains(&format!(" {} ", s.1)))
+      .count()
+  }
+}
+
+impl FromIterator<String> for SkillSet {
+  fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> SkillSet {
+    let mut skills: Vec<(String, String)> = Vec::new();
+    for skill in iter {
+      let normalized = normalize(&skill);
+      if !normalized.is_empty() && !skills.iter().any(|s| s.1 == normalized) {
+        skills.push((skill, normalized));
+      }
+    }
+
+    SkillSet { skills }
+  }
+}
@@ -0,0 +1,172 @@
+use crate::domain::availability::{Availability, DayAvailability, TimeWindow};
+use crate::domain::weekday::Weekday;
+use crate::domain::worker::Worker;
+use crate::fixtures::worker_dto;
+use chrono::{NaiveDate, NaiveTime};
+use serde_json::json;
+use std::convert::TryFrom;
+
+fn window(start: &str, end: &str) -> TimeWindow {
+  TimeWindow {
+    start: NaiveTime::parse_from_str(start, "%H:%M").unwrap(),
+    end: NaiveTime::parse_from_str(end, "%H:%M").unwrap(),
+  }
+}
+
+fn day(day: Weekday, window: Option<TimeWindow>) -> DayAvailability {
+  DayAvailability { day, window }
+}
+
+fn worker_with(
+  availability: serde_json::Value,
+  blackout_dates: serde_json::Value,
+) -> Result<Worker, Vec<String>> {
+  Worker::try_from(worker_dto(
+    1,
+    json!({ "availability": availability, "blackoutDates": blackout_dates }),
+  ))
+}
+
+fn worker(availability: serde_json::Value) -> Result<Worker, Vec<String>> {
+  worker_with(availability, json!([]))
+}
+
+#[test]
+fn test_measures_overnight_windows() {
+  assert_eq!(window("09:00", "17:00").duration_minutes(), 480);
+  assert_eq!(window("22:00", "06:00").duration_minutes(), 480);
+  assert_eq!(window("00:00", "00:00").duration_minutes(), 1440);
+}
+
+#[test]
+fn test_whole_day_availability_covers_any_shift() {
+  let availability = Availability::new(vec![day(Weekday::Monday, None)]);
+  assert_eq!(
+    availability.overlap_minutes(Weekday::Monday, &window("08:00", "16:00")),
+    480
+  );
+  assert_eq!(
+    availability.overlap_minutes(Weekday::Tuesday, &window("08:00", "16:00")),
+    0
+  );
+}
+
+#[test]
+fn test_counts_partial_and_overlapping_windows_once() {
+  let availability = Availability::new(vec![
+    day(Weekday::Monday, Some(window("07:00", "12:00"))),
+    day(Weekday::Monday, Some(window("11:00", "13:00"))),
+    day(Weekday::Monday, Some(window("15:00", "18:00"))),
+  ]);
+  assert_eq!(
+    availability.overlap_minutes(Weekday::Monday, &window("09:00", "17:00")),
+    360
+  );
+}
+
+#[test]
+fn test_follows_overnight_shifts_into_the_next_day() {
+  let availability = Availability::new(vec![
+    day(Weekday::Sunday, Some(window("20:00", "23:00"))),
+    day(Weekday::Monday, Some(window("00:00", "04:00"))),
+  ]);
+  assert_eq!(
+    availability.overlap_minutes(Weekday::Sunday, &window("22:00", "06:00")),
+    300
+  );
+
+  let availability = Availability::new(vec![day(Weekday::Sunday, Some(window("20:00", "04:00")))]);
+  assert_eq!(
+    availability.overlap_minutes(Weekday::Monday, &window("02:00", "10:00")),
+    120
+  );
+}
+
+#[test]
+fn test_parses_worker_time_windows() {
+  let worker = worker(json!([
+    { "title": "Monday", "dayIndex": 1, "startTime": "09:00", "endTime": "17:00" },
+    { "title": "Monday", "dayIndex": 1, "startTime": "09:00", "endTime": "17:00" },
+    { "title": "Tuesday", "dayIndex": 2 },
+  ]))
+  .ok()
+  .unwrap();
+  assert_eq!(
+    worker.availability,
+    Availability::new(vec![
+      DayAvailability {
+        day: Weekday::Monday,
+        window: Some(window("09:00", "17:00")),
+      },
+      DayAvailability {
+        day: Weekday::Tuesday,
+        window: None,
+      },
+    ])
+  );
+}
+
+#[test]
+fn test_reports_invalid_time_windows() {
+  let errors = worker(json!([
+    { "title": "Monday", "dayIndex": 1, "startTime": "9am", "endTime": "17:00" },
+    { "title": "Tuesday", "dayIndex": 2, "startTime": "09:00" },
+  ]))
+  .err()
+  .unwrap();
+  assert_eq!(
+    errors,
+    vec![
+      "availability.startTime: expected HH:MM, got '9am'",
+      "availability: startTime and endTime must be given together",
+    ]
+  );
+}
+
+#[test]
+fn test_finds_blackout_containing_date() {
+  let worker = worker_with(
+    json!([]),
+    json!([
+      { "startDate": "2021-06-01", "endDate": "2021-06-03" },
+      { "startDate": "2021-07-10", "endDate": "2021-07-10" },
+    ]),
+  )
+  .ok()
+  .unwrap();
+  assert_eq!(
+    worker
+      .blackout_on(NaiveDate::from_ymd(2021, 6, 3))
+      .map(|b| b.0),
+    Some(0)
+  );
+  assert_eq!(
+    worker
+      .blackout_on(NaiveDate::from_ymd(2021, 7, 10))
+      .map(|b| b.0),
+    Some(1)
+  );
+  assert!(worker
+    .blackout_on(NaiveDate::from_ymd(2021, 6, 4))
+    .is_none());
+}
+
+#[test]
+fn test_reports_invalid_blackout_dates() {
+  let errors = worker_with(
+    json!([]),
+    json!([
+      { "startDate": "2021-06-03", "endDate": "2021-06-01" },
+      { "startDate": "June 1", "endDate": "2021-06-01" },
+    ]),
+  )
+  .err()
+  .unwrap();
+  assert_eq!(
+    errors,
+    vec![
+      "blackoutDates[0]: endDate is before startDate",
+      "blackoutDates[1].startDate: expected YYYY-MM-DD, got 'June 1'",
+    ]
+  );
+}
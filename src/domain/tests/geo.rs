@@ -0,0 +1,35 @@
+use crate::domain::geo::{DistanceUnit, GeoPoint};
+
+#[test]
+fn test_parses_coordinates() {
+  let point = GeoPoint::parse("49.7", "-13.9").unwrap();
+
+  assert_eq!(point.latitude(), 49.7);
+  assert_eq!(point.longitude(), -13.9);
+}
+
+#[test]
+fn test_rejects_unparseable_coordinates() {
+  assert!(GeoPoint::parse("north", "13.9").is_err());
+}
+
+#[test]
+fn test_rejects_out_of_range_coordinates() {
+  assert!(GeoPoint::new(91.0, 0.0).is_err());
+  assert!(GeoPoint::new(0.0, -181.0).is_err());
+}
+
+#[test]
+fn test_parses_distance_units() {
+  assert_eq!(DistanceUnit::parse("km"), Ok(DistanceUnit::Kilometres));
+  assert_eq!(DistanceUnit::parse(" Miles"), Ok(DistanceUnit::Miles));
+  assert_eq!(DistanceUnit::parse("M"), Ok(DistanceUnit::Metres));
+  assert!(DistanceUnit::parse("furlong").is_err());
+}
+
+#[test]
+fn test_converts_distance_units() {
+  assert_eq!(DistanceUnit::Kilometres.convert_to_km(12.5), 12.5);
+  assert!((DistanceUnit::Miles.convert_to_km(10.0) - 16.09344).abs() < 1e-9);
+  assert!((DistanceUnit::Metres.convert_from_km(1.5) - 1500.0).abs() < 1e-9);
+}
@@ -0,0 +1,8 @@
+pub mod availability;
+pub mod geo;
+pub mod money;
+pub mod schedule;
+pub mod skills;
+pub mod timezone;
+pub mod transport;
+pub mod weekday;
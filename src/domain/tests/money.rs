@@ -0,0 +1,20 @@
+use crate::domain::money::Money;
+
+#[test]
+fn test_parses_dollar_amounts() {
+  let money = Money::parse("$10.50").unwrap();
+
+  assert_eq!(money.amount(), 10.5);
+  assert_eq!(money.to_string(), "$10.50");
+}
+
+#[test]
+fn test_parses_amounts_without_currency_symbol() {
+  assert_eq!(Money::parse("7").unwrap().to_string(), "$7.00");
+}
+
+#[test]
+fn test_rejects_invalid_amounts() {
+  assert!(Money::parse("ten dollars").is_err());
+  assert!(Money::parse("$-1.00").is_err());
+}
@@ -0,0 +1,62 @@
+use crate::domain::schedule::JobSchedule;
+use crate::dto::JobScheduleDto;
+use chrono::NaiveDate;
+use serde_json::json;
+use std::convert::TryFrom;
+
+fn schedule(value: serde_json::Value) -> Result<JobSchedule, Vec<String>> {
+  let dto: JobScheduleDto = serde_json::from_value(value).unwrap();
+  JobSchedule::try_from(dto)
+}
+
+#[test]
+fn test_lists_working_days_on_scheduled_weekdays() {
+  let schedule = schedule(json!({
+    "startDate": "2021-06-01", "endDate": "2021-06-14", "weekdays": [1, 3, 5],
+    "shiftStart": "22:00", "shiftEnd": "06:00",
+  }))
+  .unwrap();
+  let days = schedule.working_days();
+  assert_eq!(days.len(), 6);
+  assert_eq!(days[0], NaiveDate::from_ymd(2021, 6, 2));
+  assert_eq!(days[5], NaiveDate::from_ymd(2021, 6, 14));
+}
+
+#[test]
+fn test_works_every_day_without_weekdays() {
+  let schedule = schedule(json!({ "startDate": "2021-06-01", "endDate": "2021-06-07" })).unwrap();
+  assert_eq!(schedule.working_days().len(), 7);
+}
+
+#[test]
+fn test_reports_invalid_schedules() {
+  let errors = schedule(json!({
+    "startDate": "2021-06-01", "endDate": "01/07/2021", "weekdays": [0],
+    "shiftStart": "9am", "minimumCoverage": 1.5,
+  }))
+  .err()
+  .unwrap();
+  assert_eq!(
+    errors,
+    vec![
+      "schedule.endDate: expected YYYY-MM-DD, got '01/07/2021'",
+      "schedule.weekdays: invalid day index 0",
+      "schedule: shiftStart and shiftEnd must be given together",
+      "schedule.minimumCoverage: must be between 0 and 1, got 1.5",
+    ]
+  );
+
+  let errors = schedule(json!({ "startDate": "2021-06-07", "endDate": "2021-06-01" }))
+    .err()
+    .unwrap();
+  assert_eq!(errors, vec!["schedule: endDate is before startDate"]);
+
+  let errors =
+    schedule(json!({ "startDate": "2021-06-01", "endDate": "2021-06-04", "weekdays": [6, 7] }))
+      .err()
+      .unwrap();
+  assert_eq!(
+    errors,
+    vec!["schedule: no working days between startDate and endDate"]
+  );
+}
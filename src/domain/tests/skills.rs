@@ -0,0 +1,42 @@
+use crate::domain::skills::{normalize, SkillSet};
+
+fn skill_set(skills: &[&str]) -> SkillSet {
+  skills.iter().map(|s| String::from(*s)).collect()
+}
+
+#[test]
+fn test_normalizes_case_and_punctuation() {
+  assert_eq!(
+    normalize("  Forklift-Operator, Level 2 "),
+    "forklift operator level 2"
+  );
+}
+
+#[test]
+fn test_ignores_duplicate_skills() {
+  let skills = skill_set(&["Cashier", "cashier ", "Driver"]);
+
+  assert_eq!(skills.len(), 2);
+  assert_eq!(
+    skills.iter().collect::<Vec<&String>>(),
+    vec!["Cashier", "Driver"]
+  );
+}
+
+#[test]
+fn test_counts_skills_found_in_other_set() {
+  let required = skill_set(&["Cashier", "Forklift Operator", "Barista"]);
+  let worker = skill_set(&["forklift operator", "CASHIER"]);
+
+  assert_eq!(required.count_found_in(&worker), 2);
+}
+
+#[test]
+fn test_counts_whole_word_mentions() {
+  let worker = skill_set(&["Cashier", "Driver", "Art"]);
+
+  assert_eq!(
+    worker.count_mentioned_in(&normalize("Cashier work, party starts at 9")),
+    1
+  );
+}
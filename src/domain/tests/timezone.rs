@@ -0,0 +1,75 @@
+use crate::domain::config::TimezoneConfig;
+use crate::domain::job::Job;
+use crate::domain::timezone::TimezoneResolver;
+use crate::fixtures;
+use chrono::{Datelike, Timelike};
+use serde_json::json;
+
+fn job(start_date: &str, timezone: Option<&str>) -> Job {
+  fixtures::job(
+    1,
+    json!({
+      "location": { "latitude": "-33.87", "longitude": "151.21" },
+      "startDate": start_date, "timezone": timezone,
+    }),
+  )
+}
+
+fn resolver(config: serde_json::Value) -> TimezoneResolver {
+  let config: TimezoneConfig = serde_json::from_value(config).unwrap();
+  TimezoneResolver::new(&config).ok().unwrap()
+}
+
+#[test]
+fn test_keeps_upstream_offset_without_timezone() {
+  let start = resolver(json!({})).local_start(&job("2021-06-06T23:00:00.000Z", None));
+  assert_eq!(start.weekday(), chrono::Weekday::Sun);
+  assert_eq!(start.hour(), 23);
+}
+
+#[test]
+fn test_uses_job_timezone_before_regions_and_default() {
+  let resolver = resolver(json!({
+    "default": "UTC",
+    "regions": [{ "timezone": "Australia/Sydney", "min_latitude": -44, "max_latitude": -10,
+      "min_longitude": 112, "max_longitude": 154 }],
+  }));
+
+  let start = resolver.local_start(&job("2021-06-06T23:00:00.000Z", None));
+  assert_eq!(start.weekday(), chrono::Weekday::Mon);
+  assert_eq!(start.day(), 7);
+
+  let start = resolver.local_start(&job("2021-06-06T23:00:00.000Z", Some("America/New_York")));
+  assert_eq!(start.weekday(), chrono::Weekday::Sun);
+  assert_eq!(start.hour(), 19);
+}
+
+#[test]
+fn test_follows_daylight_saving_transitions() {
+  let resolver = resolver(json!({ "default": "America/New_York" }));
+
+  let before = resolver.local_start(&job("2021-11-07T03:30:00.000Z", None));
+  assert_eq!((before.day(), before.hour()), (6, 23));
+  assert_eq!(before.offset().local_minus_utc(), -4 * 3600);
+
+  let after = resolver.local_start(&job("2021-11-07T06:30:00.000Z", None));
+  assert_eq!((after.day(), after.hour()), (7, 1));
+  assert_eq!(after.offset().local_minus_utc(), -5 * 3600);
+}
+
+#[test]
+fn test_reports_unknown_timezones() {
+  let config: TimezoneConfig = serde_json::from_value(json!({
+    "default": "Mars/Olympus_Mons",
+    "regions": [{ "timezone": "UTC", "min_latitude": 10, "max_latitude": 0,
+      "min_longitude": 0, "max_longitude": 10 }],
+  }))
+  .unwrap();
+  assert_eq!(
+    TimezoneResolver::new(&config).err().unwrap(),
+    vec![
+      "timezones.default: unknown timezone 'Mars/Olympus_Mons'",
+      "timezones.regions[0]: minimum bounds must not exceed maximum bounds",
+    ]
+  );
+}
@@ -0,0 +1,22 @@
+use crate::domain::transport::TransportMode;
+
+#[test]
+fn test_parses_known_modes() {
+  assert_eq!(TransportMode::parse("CAR"), TransportMode::Car);
+  assert_eq!(
+    TransportMode::parse("public_transport"),
+    TransportMode::PublicTransport
+  );
+  assert_eq!(
+    TransportMode::parse("PUBLIC TRANSPORT"),
+    TransportMode::PublicTransport
+  );
+  assert_eq!(TransportMode::parse("Walk"), TransportMode::Walk);
+}
+
+#[test]
+fn test_keeps_unknown_modes() {
+  let mode = TransportMode::parse("scooter");
+  assert_eq!(mode, TransportMode::Other(String::from("SCOOTER")));
+  assert_eq!(mode.label(), "SCOOTER");
+}
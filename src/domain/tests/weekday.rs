@@ -0,0 +1,27 @@
+use crate::domain::weekday::Weekday;
+
+#[test]
+fn test_converts_day_index() {
+  assert_eq!(Weekday::from_day_index(1), Some(Weekday::Monday));
+  assert_eq!(Weekday::from_day_index(7), Some(Weekday::Sunday));
+  assert_eq!(Weekday::Friday.day_index(), 5);
+}
+
+#[test]
+fn test_rejects_invalid_day_index() {
+  assert_eq!(Weekday::from_day_index(0), None);
+  assert_eq!(Weekday::from_day_index(8), None);
+}
+
+#[test]
+fn test_converts_chrono_weekday() {
+  assert_eq!(Weekday::from(chrono::Weekday::Mon), Weekday::Monday);
+  assert_eq!(Weekday::from(chrono::Weekday::Sun), Weekday::Sunday);
+}
+
+#[test]
+fn test_adds_days_across_week_boundary() {
+  assert_eq!(Weekday::Sunday.plus_days(1), Weekday::Monday);
+  assert_eq!(Weekday::Monday.plus_days(-1), Weekday::Sunday);
+  assert_eq!(Weekday::Wednesday.plus_days(0), Weekday::Wednesday);
+}
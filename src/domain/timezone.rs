@@ -0,0 +1,97 @@
+use super::config::TimezoneConfig;
+use super::geo::GeoPoint;
+use super::job::Job;
+use chrono::{DateTime, FixedOffset, Offset};
+use chrono_tz::Tz;
+
+struct TimezoneRegion {
+  timezone: Tz,
+  min_latitude: f64,
+  max_latitude: f64,
+  min_longitude: f64,
+  max_longitude: f64,
+}
+
+impl TimezoneRegion {
+  fn contains(&self, point: &GeoPoint) -> bool {
+    (self.min_latitude..=self.max_latitude).contains(&point.latitude())
+      && (self.min_longitude..=self.max_longitude).contains(&point.longitude())
+  }
+}
+
+pub struct TimezoneResolver {
+  default: Option<Tz>,
+  regions: Vec<TimezoneRegion>,
+}
+
+fn parse_timezone(name: &str, field: String, errors: &mut Vec<String>) -> Option<Tz> {
+  match name.parse::<Tz>() {
+    Ok(timezone) => Some(timezone),
+    Err(_) => {
+      errors.push(format!("{}: unknown timezone '{}'", field, name));
+      None
+    }
+  }
+}
+
+impl TimezoneResolver {
+  pub fn new(config: &TimezoneConfig) -> Result<TimezoneResolver, Vec<String>> {
+    let mut errors: Vec<String> = Vec::new();
+    let default = config
+      .default
+      .as_ref()
+      .and_then(|name| parse_timezone(name, String::from("timezones.default"), &mut errors));
+    let mut regions: Vec<TimezoneRegion> = Vec::new();
+    for (position, region) in config.regions.iter().enumerate() {
+      let field = format!("timezones.regions[{}]", position);
+      if region.min_latitude > region.max_latitude || region.min_longitude > region.max_longitude {
+        errors.push(format!(
+          "{}: minimum bounds must not exceed maximum bounds",
+          field
+        ));
+      }
+      if let Some(timezone) = parse_timezone(&region.timezone, field, &mut errors) {
+        regions.push(TimezoneRegion {
+          timezone,
+          min_latitude: region.min_latitude,
+          max_latitude: region.max_latitude,
+          min_longitude: region.min_longitude,
+          max_longitude: region.max_longitude,
+        });
+      }
+    }
+
+    if errors.is_empty() {
+      Ok(TimezoneResolver { default, regions })
+    } else {
+      Err(errors)
+    }
+  }
+
+  pub fn resolve(&self, job: &Job) -> Option<Tz> {
+    job
+      .timezone
+      .or_else(|| {
+        self
+          .regions
+          .iter()
+          .find(|r| job.location.map_or(false, |l| r.contains(&l)))
+          .map(|r| r.timezone)
+      })
+      .or(self.default)
+  }
+
+  pub fn localize(&self, job: &Job, instant: &DateTime<FixedOffset>) -> DateTime<FixedOffset> {
+    match self.resolve(job) {
+      Some(timezone) => {
+        let local = instant.with_timezone(&timezone);
+        local.with_timezone(&local.offset().fix())
+      }
+      None => *instant,
+    }
+  }
+
+  pub fn local_start(&self, job: &Job) -> DateTime<FixedOffset> {
+    self.localize(job, &job.start_date)
+  }
+}
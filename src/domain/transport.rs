@@ -0,0 +1,31 @@
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TransportMode {
+  Car,
+  PublicTransport,
+  Bike,
+  Walk,
+  Other(String),
+}
+
+impl TransportMode {
+  pub fn parse(mode: &str) -> TransportMode {
+    let normalized = mode.trim().to_uppercase().replace('_', " ");
+    match normalized.as_str() {
+      "CAR" => TransportMode::Car,
+      "PUBLIC TRANSPORT" => TransportMode::PublicTransport,
+      "BIKE" | "BICYCLE" => TransportMode::Bike,
+      "WALK" | "WALKING" => TransportMode::Walk,
+      _ => TransportMode::Other(normalized),
+    }
+  }
+
+  pub fn label(&self) -> &str {
+    match self {
+      TransportMode::Car => "CAR",
+      TransportMode::PublicTransport => "PUBLIC TRANSPORT",
+      TransportMode::Bike => "BIKE",
+      TransportMode::Walk => "WALK",
+      TransportMode::Other(mode) => mode,
+    }
+  }
+}
@@ -0,0 +1,43 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Weekday {
+  Monday,
+  Tuesday,
+  Wednesday,
+  Thursday,
+  Friday,
+  Saturday,
+  Sunday,
+}
+
+const WEEKDAYS: [Weekday; 7] = [
+  Weekday::Monday,
+  Weekday::Tuesday,
+  Weekday::Wednesday,
+  Weekday::Thursday,
+  Weekday::Friday,
+  Weekday::Saturday,
+  Weekday::Sunday,
+];
+
+impl Weekday {
+  pub fn from_day_index(day_index: u32) -> Option<Weekday> {
+    match day_index {
+      1..=7 => Some(WEEKDAYS[day_index as usize - 1]),
+      _ => None,
+    }
+  }
+
+  pub fn day_index(&self) -> u32 {
+    *self as u32 + 1
+  }
+
+  pub fn plus_days(&self, days: i64) -> Weekday {
+    WEEKDAYS[(*self as i64 + days).rem_euclid(7) as usize]
+  }
+}
+
+impl From<chrono::Weekday> for Weekday {
+  fn from(weekday: chrono::Weekday) -> Weekday {
+    WEEKDAYS[weekday.num_days_from_monday() as usize]
+  }
+}
@@ -0,0 +1,196 @@
+use super::availability::{
+  Availability, DateRange, DayAvailability, TimeWindow, DATE_FORMAT, TIME_FORMAT,
+};
+use super::certificates::CertificateSet;
+use super::geo::{DistanceUnit, GeoPoint};
+use super::skills::SkillSet;
+use super::transport::TransportMode;
+use super::weekday::Weekday;
+use crate::dto::WorkerDto;
+use chrono::{NaiveDate, NaiveTime};
+use std::convert::TryFrom;
+
+#[derive(Clone)]
+pub struct SearchArea {
+  pub location: Option<GeoPoint>,
+  pub max_job_distance: f64,
+  pub unit: Option<DistanceUnit>,
+}
+
+impl SearchArea {
+  /// `None` when the unit could not be parsed.
+  pub fn max_job_distance_km(&self) -> Option<f64> {
+    self.unit.map(|u| u.convert_to_km(self.max_job_distance))
+  }
+}
+
+#[derive(Clone)]
+pub struct Worker {
+  pub user_id: u32,
+  pub guid: String,
+  pub active: bool,
+  pub phone: String,
+  pub email: String,
+  pub first_name: String,
+  pub last_name: String,
+  pub age: u32,
+  pub rating: u32,
+  pub certificates: CertificateSet,
+  pub skills: SkillSet,
+  pub job_search_address: SearchArea,
+  pub transportation: TransportMode,
+  pub max_commute_minutes: Option<u32>,
+  pub has_drivers_license: bool,
+  pub availability: Availability,
+  pub blackout_dates: Vec<DateRange>,
+  /// The record as received, returned unchanged by the API and stored as is.
+  pub source: WorkerDto,
+}
+
+impl Worker {
+  pub fn blackout_on(&self, date: NaiveDate) -> Option<(usize, &DateRange)> {
+    self
+      .blackout_dates
+      .iter()
+      .enumerate()
+      .find(|(_, range)| range.contains(date))
+  }
+}
+
+fn parse_time(field: &str, value: &str) -> Result<NaiveTime, String> {
+  NaiveTime::parse_from_str(value, TIME_FORMAT)
+    .map_err(|_| format!("availability.{}: expected HH:MM, got '{}'", field, value))
+}
+
+fn parse_date(field: &str, value: &str) -> Result<NaiveDate, String> {
+  NaiveDate::parse_from_str(value, DATE_FORMAT)
+    .map_err(|_| format!("{}: expected YYYY-MM-DD, got '{}'", field, value))
+}
+
+impl Worker {
+  /// Converts an upstream record. Unparseable coordinates, distance units and
+  /// availability entries are returned as warnings next to the worker, leaving
+  /// the value unset or the entry out; any other problem rejects the record.
+  pub fn parse(dto: WorkerDto) -> Result<(Worker, Vec<String>), Vec<String>> {
+    let source = dto.clone();
+    let mut problems = Vec::new();
+    let mut warnings = Vec::new();
+    let location = GeoPoint::parse(
+      &dto.job_search_address.latitude,
+      &dto.job_search_address.longitude,
+    )
+    .map_err(|e| warnings.push(format!("jobSearchAddress: {}", e)))
+    .ok();
+    let unit = DistanceUnit::parse(&dto.job_search_address.unit)
+      .map_err(|e| warnings.push(format!("jobSearchAddress.unit: {}", e)))
+      .ok();
+    let max_job_distance = dto.job_search_address.max_job_distance;
+    if max_job_distance.is_nan() || max_job_distance < 0.0 {
+      problems.push(format!(
+        "jobSearchAddress.maxJobDistance {} must not be negative",
+        max_job_distance
+      ));
+    }
+    let mut availability = Vec::new();
+    for day in dto.availability.iter().flatten() {
+      let weekday = Weekday::from_day_index(day.day_index);
+      if weekday.is_none() {
+        warnings.push(format!(
+          "availability.dayIndex {} is not a valid day",
+          day.day_index
+        ));
+      }
+      let window = match (&day.start_time, &day.end_time) {
+        (Some(start), Some(end)) => {
+          match (parse_time("startTime", start), parse_time("endTime", end)) {
+            (Ok(start), Ok(end)) => Some(TimeWindow { start, end }),
+            (start, end) => {
+              warnings.extend(start.err());
+              warnings.extend(end.err());
+              continue;
+            }
+          }
+        }
+        (None, None) => None,
+        _ => {
+          warnings.push(String::from(
+            "availability: startTime and endTime must be given together",
+          ));
+          continue;
+        }
+      };
+      if let Some(day) = weekday {
+        availability.push(DayAvailability { day, window });
+      }
+    }
+    let mut blackout_dates = Vec::new();
+    for (position, range) in dto.blackout_dates.iter().enumerate() {
+      let start = parse_date(
+        &format!("blackoutDates[{}].startDate", position),
+        &range.start_date,
+      );
+      let end = parse_date(
+        &format!("blackoutDates[{}].endDate", position),
+        &range.end_date,
+      );
+      match (start, end) {
+        (Ok(start), Ok(end)) if end < start => problems.push(format!(
+          "blackoutDates[{}]: endDate is before startDate",
+          position
+        )),
+        (Ok(start), Ok(end)) => blackout_dates.push(DateRange { start, end }),
+        (start, end) => {
+          problems.extend(start.err());
+          problems.extend(end.err());
+        }
+      }
+    }
+    if !problems.is_empty() {
+      return Err(problems);
+    }
+
+    let worker = Worker {
+      user_id: dto.user_id,
+      guid: dto.guid,
+      active: dto.active,
+      phone: dto.phone,
+      email: dto.email,
+      first_name: dto.name.first,
+      last_name: dto.name.last,
+      age: dto.age,
+      rating: dto.rating,
+      certificates: dto.certificates.into_iter().flatten().collect(),
+      skills: dto.skills.into_iter().collect(),
+      job_search_address: SearchArea {
+        location,
+        max_job_distance: dto.job_search_address.max_job_distance,
+        unit,
+      },
+      transportation: TransportMode::parse(&dto.transportation),
+      max_commute_minutes: dto.max_commute_minutes,
+      has_drivers_license: dto.has_drivers_license,
+      availability: Availability::new(availability),
+      blackout_dates,
+      source,
+    };
+
+    Ok((worker, warnings))
+  }
+}
+
+impl TryFrom<WorkerDto> for Worker {
+  type Error = Vec<String>;
+
+  fn try_from(dto: WorkerDto) -> Result<Worker, Vec<String>> {
+    match Worker::parse(dto)? {
+      (worker, warnings) if warnings.is_empty() => Ok(worker),
+      (_, warnings) => Err(warnings),
+    }
+  }
+}
+
+impl From<&Worker> for WorkerDto {
+  fn from(worker: &Worker) -> WorkerDto {
+    worker.source.clone()
+  }
+}
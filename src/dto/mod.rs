@@ -1,4 +1,4 @@
-use chrono::{DateTime, FixedOffset};
+use chrono::{DateTime, FixedOffset, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -8,6 +8,30 @@ pub struct GeographicLocationDto {
   pub longitude: String,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+pub struct JobScheduleDto {
+  #[serde(rename = "startDate")]
+  pub start_date: String,
+  #[serde(rename = "endDate")]
+  pub end_date: String,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub weekdays: Vec<u32>,
+  #[serde(
+    rename = "shiftStart",
+    default,
+    skip_serializing_if = "Option::is_none"
+  )]
+  pub shift_start: Option<String>,
+  #[serde(rename = "shiftEnd", default, skip_serializing_if = "Option::is_none")]
+  pub shift_end: Option<String>,
+  #[serde(
+    rename = "minimumCoverage",
+    default,
+    skip_serializing_if = "Option::is_none"
+  )]
+  pub minimum_coverage: Option<f64>,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct JobDto {
   #[serde(rename = "jobId")]
@@ -26,12 +50,34 @@ pub struct JobDto {
   pub start_date: DateTime<FixedOffset>,
   pub about: String,
   pub company: String,
+  #[serde(
+    rename = "requiredSkills",
+    default,
+    skip_serializing_if = "Option::is_none"
+  )]
+  pub required_skills: Option<Vec<String>>,
+  #[serde(
+    rename = "preferredSkills",
+    default,
+    skip_serializing_if = "Option::is_none"
+  )]
+  pub preferred_skills: Option<Vec<String>>,
+  #[serde(
+    rename = "minimumRating",
+    default,
+    skip_serializing_if = "Option::is_none"
+  )]
+  pub minimum_rating: Option<u32>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub timezone: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub schedule: Option<JobScheduleDto>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct NameDto {
-  first: String,
-  last: String,
+  pub first: String,
+  pub last: String,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -48,6 +94,10 @@ pub struct DayDto {
   pub title: String,
   #[serde(rename = "dayIndex")]
   pub day_index: u32,
+  #[serde(rename = "startTime", default, skip_serializing_if = "Option::is_none")]
+  pub start_time: Option<String>,
+  #[serde(rename = "endTime", default, skip_serializing_if = "Option::is_none")]
+  pub end_time: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -70,6 +120,26 @@ pub struct WorkerDto {
   #[serde(rename = "hasDriversLicense")]
   pub has_drivers_license: bool,
   pub availability: Vec<Option<DayDto>>,
+  #[serde(
+    rename = "maxCommuteMinutes",
+    default,
+    skip_serializing_if = "Option::is_none"
+  )]
+  pub max_commute_minutes: Option<u32>,
+  #[serde(
+    rename = "blackoutDates",
+    default,
+    skip_serializing_if = "Vec::is_empty"
+  )]
+  pub blackout_dates: Vec<DateRangeDto>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DateRangeDto {
+  #[serde(rename = "startDate")]
+  pub start_date: String,
+  #[serde(rename = "endDate")]
+  pub end_date: String,
 }
 
 #[derive(Serialize)]
@@ -78,12 +148,24 @@ pub struct RuleConfigDto {
   pub weight: f64,
 }
 
+#[derive(Serialize, Clone)]
+pub struct RejectionDto {
+  #[serde(rename = "ruleName")]
+  pub rule_name: String,
+  pub code: String,
+  pub message: String,
+}
+
 #[derive(Serialize, Clone)]
 pub struct RuleResultDto {
   #[serde(rename = "ruleName")]
   pub rule_name: String,
   pub weight: f64,
+  pub outcome: String,
+  pub score: f64,
   pub rating: f64,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub rejection: Option<RejectionDto>,
   pub metrics: HashMap<String, f64>,
 }
 
@@ -94,6 +176,8 @@ pub struct MatchScoreDto {
   #[serde(rename = "jobId")]
   pub job_id: u32,
   pub rating: f64,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub rejections: Vec<RejectionDto>,
   pub rule_results: Vec<RuleResultDto>,
 }
 
@@ -102,11 +186,78 @@ pub struct StackDiagnosisResponse {
   pub jobs: Vec<MatchScoreDto>,
   #[serde(rename = "calculationTimeMs")]
   pub calculation_time_ms: u128,
+  #[serde(rename = "staleData")]
+  pub stale_data: bool,
+}
+
+#[derive(Serialize)]
+pub struct ExcludedWorkerDto {
+  #[serde(rename = "workerId")]
+  pub worker_id: u32,
+  pub reason: String,
 }
 
 #[derive(Serialize)]
 pub struct WorkersDiagnosisResponse {
   pub workers: Vec<MatchScoreDto>,
+  pub excluded: Vec<ExcludedWorkerDto>,
   #[serde(rename = "calculationTimeMs")]
   pub calculation_time_ms: u128,
+  #[serde(rename = "staleData")]
+  pub stale_data: bool,
+}
+
+/// Returned by `countWorkersforJob?withReasons=true`. `rejections` counts each
+/// rejected worker once, under the code of the first rule that rejected it.
+#[derive(Serialize)]
+pub struct WorkerCountResponse {
+  pub count: usize,
+  pub rejections: HashMap<String, usize>,
+}
+
+#[derive(Serialize)]
+pub struct CacheDatasetStatusDto {
+  pub size: usize,
+  #[serde(rename = "ageSeconds")]
+  pub age_seconds: Option<u64>,
+  #[serde(rename = "lastRefresh")]
+  pub last_refresh: Option<DateTime<Utc>>,
+  #[serde(rename = "lastRefreshError")]
+  pub last_refresh_error: Option<String>,
+  pub hits: u64,
+  pub misses: u64,
+  pub stale: bool,
+  #[serde(rename = "duplicateIds")]
+  pub duplicate_ids: Vec<u32>,
+  #[serde(rename = "duplicateGuids")]
+  pub duplicate_guids: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct CacheStatusDto {
+  pub enabled: bool,
+  #[serde(rename = "ttlSeconds")]
+  pub ttl_seconds: u64,
+  #[serde(rename = "maxStalenessSeconds")]
+  pub max_staleness_seconds: u64,
+  pub workers: Option<CacheDatasetStatusDto>,
+  pub jobs: Option<CacheDatasetStatusDto>,
+}
+
+#[derive(Serialize, Clone)]
+pub struct DataQualityIssueDto {
+  pub id: Option<u32>,
+  pub guid: Option<String>,
+  pub kind: String,
+  pub message: String,
+  pub skipped: bool,
+}
+
+#[derive(Serialize, Clone)]
+pub struct DataQualityDatasetDto {
+  #[serde(rename = "checkedAt")]
+  pub checked_at: DateTime<Utc>,
+  pub records: usize,
+  pub skipped: usize,
+  pub issues: Vec<DataQualityIssueDto>,
 }
@@ -1,16 +1,32 @@
 use super::config::EvaluationContext;
+use super::match_rating::date_metric;
 use super::match_rating::MatchRating;
+use super::match_rating::RatingOutcome;
 use super::match_rating::RatingResult;
-use chrono::Datelike;
+use super::match_rating::RejectionReason;
+use crate::domain::timezone::TimezoneResolver;
+use crate::domain::weekday::Weekday;
+use chrono::{Datelike, Offset};
 use std::collections::HashMap;
+use std::sync::Arc;
 
 pub struct AvailableOnStartDay {
   weight_value: f64,
+  timezones: Arc<TimezoneResolver>,
+  minimum_shift_overlap: Option<f64>,
 }
 
 impl AvailableOnStartDay {
-  pub fn new(weight_value: f64) -> AvailableOnStartDay {
-    AvailableOnStartDay { weight_value }
+  pub fn new(
+    weight_value: f64,
+    timezones: Arc<TimezoneResolver>,
+    minimum_shift_overlap: Option<f64>,
+  ) -> AvailableOnStartDay {
+    AvailableOnStartDay {
+      weight_value,
+      timezones,
+      minimum_shift_overlap,
+    }
   }
 }
 
@@ -30,27 +46,85 @@ impl MatchRating for AvailableOnStartDay {
       ctx.worker.user_id,
       ctx.job.job_id
     );
-    let day_required_idx = ctx.job.start_date.weekday().num_days_from_monday() + 1;
-    let has_start_day = ctx
-      .worker
-      .availability
-      .iter()
-      .filter(|d| match d {
-        Some(day) => day.day_index == day_required_idx,
-        None => false,
-      })
-      .count();
-    let rating = if has_start_day > 0 {
-      self.weight_value
-    } else {
-      0.0
+    let local_start = self.timezones.local_start(ctx.job);
+    let start_date = local_start.date().naive_local();
+    let start_day = Weekday::from(local_start.weekday());
+    let has_start_day = ctx.worker.availability.is_available_on(start_day);
+    let shift = ctx.job.schedule.as_ref().and_then(|s| s.shift);
+    let shift_overlap = shift.map(|shift| {
+      (
+        ctx.worker.availability.overlap_minutes(start_day, &shift),
+        shift.duration_minutes(),
+      )
+    });
+    let score = match shift_overlap {
+      Some((overlap_minutes, shift_minutes)) => overlap_minutes as f64 / shift_minutes as f64,
+      None if has_start_day => 1.0,
+      None => 0.0,
     };
     let mut metrics: HashMap<String, f64> = HashMap::new();
     if ctx.config.with_diagnosis {
-      metrics.insert(String::from("hasStartDay"), has_start_day as f64);
+      metrics.insert(
+        String::from("hasStartDay"),
+        if has_start_day { 1.0 } else { 0.0 },
+      );
+      metrics.insert(String::from("localStartDate"), date_metric(start_date));
+      metrics.insert(String::from("startDayIndex"), start_day.day_index() as f64);
+      metrics.insert(
+        String::from("utcOffsetMinutes"),
+        (local_start.offset().fix().local_minus_utc() / 60) as f64,
+      );
+      if let Some((overlap_minutes, shift_minutes)) = shift_overlap {
+        metrics.insert(String::from("overlapMinutes"), overlap_minutes as f64);
+        metrics.insert(String::from("shiftMinutes"), shift_minutes as f64);
+      }
+    }
+
+    if let Some((position, range)) = ctx.worker.blackout_on(start_date) {
+      log::debug!(
+        "Job starts on {}, during blackout {} of worker {}",
+        start_date,
+        position,
+        ctx.worker.user_id
+      );
+      if ctx.config.with_diagnosis {
+        metrics.insert(String::from("blackoutIndex"), position as f64);
+        metrics.insert(String::from("blackoutStartDate"), date_metric(range.start));
+        metrics.insert(String::from("blackoutEndDate"), date_metric(range.end));
+      }
+      return RatingResult {
+        outcome: RatingOutcome::Reject(RejectionReason::BlackoutDate {
+          start: range.start,
+          end: range.end,
+        }),
+        metrics,
+      };
     }
 
-    log::debug!("Rule {} completed; score: {}", self.get_name(), rating);
-    RatingResult { rating, metrics }
+    if let (Some((overlap_minutes, shift_minutes)), Some(minimum_shift_overlap)) =
+      (shift_overlap, self.minimum_shift_overlap)
+    {
+      if score < minimum_shift_overlap {
+        log::debug!(
+          "Worker is available for {} of {} shift minutes, below the minimum of {}",
+          overlap_minutes,
+          shift_minutes,
+          minimum_shift_overlap
+        );
+        return RatingResult {
+          outcome: RatingOutcome::Reject(RejectionReason::InsufficientShiftOverlap {
+            overlap_minutes,
+            shift_minutes,
+          }),
+          metrics,
+        };
+      }
+    }
+
+    log::debug!("Rule {} completed; score: {}", self.get_name(), score);
+    RatingResult {
+      outcome: RatingOutcome::Pass(score),
+      metrics,
+    }
   }
 }
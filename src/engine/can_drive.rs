@@ -1,12 +1,16 @@
 use super::config::EvaluationContext;
-use super::match_rating::{MatchRating, RatingResult};
+use super::match_rating::{MatchRating, RatingOutcome, RatingResult, RejectionReason};
 use std::collections::HashMap;
 
-pub struct CanDrive {}
+pub struct CanDrive {
+  weight_value: f64,
+}
 
 impl CanDrive {
-  pub fn new() -> CanDrive {
-    CanDrive {}
+  pub fn new(weight: f64) -> CanDrive {
+    CanDrive {
+      weight_value: weight,
+    }
   }
 }
 
@@ -16,7 +20,7 @@ impl MatchRating for CanDrive {
   }
 
   fn get_weight(&self) -> f64 {
-    1.0
+    self.weight_value
   }
 
   fn determine_rating(&self, ctx: &EvaluationContext) -> RatingResult {
@@ -52,16 +56,25 @@ impl MatchRating for CanDrive {
         self.get_name()
       );
       RatingResult {
-        rating: -1.0,
+        outcome: RatingOutcome::Reject(RejectionReason::DriversLicenseRequired),
         metrics,
       }
-    } else {
+    } else if ctx.job.driver_license_required {
       log::debug!(
         "Worker meets drivers license requirement: {} rule passes",
         self.get_name()
       );
       RatingResult {
-        rating: 0.0,
+        outcome: RatingOutcome::Pass(0.0),
+        metrics,
+      }
+    } else {
+      log::debug!(
+        "Job does not require a drivers license; {} does not apply",
+        self.get_name()
+      );
+      RatingResult {
+        outcome: RatingOutcome::NotApplicable,
         metrics,
       }
     }
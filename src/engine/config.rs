@@ -1,4 +1,5 @@
-use crate::dto::{JobDto, WorkerDto};
+use crate::domain::job::Job;
+use crate::domain::worker::Worker;
 
 pub struct EvaluationConfig {
   pub with_diagnosis: bool,
@@ -6,15 +7,15 @@ pub struct EvaluationConfig {
 }
 
 pub struct EvaluationContext<'w, 'j, 'c> {
-  pub worker: &'w WorkerDto,
-  pub job: &'j JobDto,
+  pub worker: &'w Worker,
+  pub job: &'j Job,
   pub config: &'c EvaluationConfig,
 }
 
 impl EvaluationContext<'_, '_, '_> {
   pub fn new<'w, 'j, 'c>(
-    worker: &'w WorkerDto,
-    job: &'j JobDto,
+    worker: &'w Worker,
+    job: &'j Job,
     config: &'c EvaluationConfig,
   ) -> EvaluationContext<'w, 'j, 'c> {
     EvaluationContext {
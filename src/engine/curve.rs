@@ -0,0 +1,91 @@
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum ScoringCurve {
+  Linear {
+    zero: f64,
+    one: f64,
+  },
+  ExponentialDecay {
+    half_life: f64,
+  },
+  Logistic {
+    midpoint: f64,
+    steepness: f64,
+  },
+  Step {
+    threshold: f64,
+    #[serde(default)]
+    below: f64,
+    #[serde(default = "default_step_above")]
+    above: f64,
+  },
+  Piecewise {
+    points: Vec<(f64, f64)>,
+  },
+}
+
+fn default_step_above() -> f64 {
+  1.0
+}
+
+impl ScoringCurve {
+  pub fn validate(&self) -> Result<(), String> {
+    match self {
+      ScoringCurve::Linear { zero, one } if zero == one => Err(String::from(
+        "linear curve needs different 'zero' and 'one' values",
+      )),
+      ScoringCurve::ExponentialDecay { half_life } if half_life.is_nan() || *half_life <= 0.0 => {
+        Err(String::from(
+          "exponential_decay curve needs a positive 'half_life'",
+        ))
+      }
+      ScoringCurve::Logistic { steepness, .. } if *steepness == 0.0 => {
+        Err(String::from("logistic curve needs a non-zero 'steepness'"))
+      }
+      ScoringCurve::Piecewise { points } if points.len() < 2 => {
+        Err(String::from("piecewise curve needs at least two points"))
+      }
+      ScoringCurve::Piecewise { points } if points.windows(2).any(|p| p[0].0 >= p[1].0) => Err(
+        String::from("piecewise curve points must be in increasing order of x"),
+      ),
+      _ => Ok(()),
+    }
+  }
+
+  pub fn apply(&self, x: f64) -> f64 {
+    match self {
+      ScoringCurve::Linear { zero, one } => ((x - zero) / (one - zero)).max(0.0).min(1.0),
+      ScoringCurve::ExponentialDecay { half_life } => 0.5f64.powf(x.max(0.0) / half_life),
+      ScoringCurve::Logistic {
+        midpoint,
+        steepness,
+      } => 1.0 / (1.0 + (-steepness * (x - midpoint)).exp()),
+      ScoringCurve::Step {
+        threshold,
+        below,
+        above,
+      } => {
+        if x < *threshold {
+          *below
+        } else {
+          *above
+        }
+      }
+      ScoringCurve::Piecewise { points } => {
+        let first = points[0];
+        let last = points[points.len() - 1];
+        if x <= first.0 {
+          return first.1;
+        }
+        if x >= last.0 {
+          return last.1;
+        }
+        let segment = points.windows(2).find(|p| x <= p[1].0).unwrap();
+        let ((x0, y0), (x1, y1)) = (segment[0], segment[1]);
+        y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+      }
+    }
+  }
+}
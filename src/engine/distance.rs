@@ -1,16 +1,16 @@
-use crate::dto::{GeographicAreaDto, GeographicLocationDto};
+use crate::domain::geo::GeoPoint;
 use std::f64::consts::PI;
-use std::num::ParseFloatError;
 
 const LAT_TO_KM: f64 = 110.574;
-const LONG_TO_KM_C: f64 = 11.320;
+const LONG_TO_KM_C: f64 = 111.320;
+const EARTH_RADIUS_KM: f64 = 6371.0088;
+const WGS84_A: f64 = 6_378_137.0;
+const WGS84_F: f64 = 1.0 / 298.257_223_563;
+const VINCENTY_MAX_ITERATIONS: u32 = 200;
+const VINCENTY_TOLERANCE: f64 = 1e-12;
 
 pub trait GeographicDistanceEvaluator {
-  fn determine_distance(
-    &self,
-    location1: &GeographicAreaDto,
-    location2: &GeographicLocationDto,
-  ) -> Result<f64, ParseFloatError>;
+  fn determine_distance(&self, location1: &GeoPoint, location2: &GeoPoint) -> f64;
 }
 
 pub struct PythagorasDistanceEvaluator {}
@@ -30,18 +30,106 @@ impl PythagorasDistanceEvaluator {
 }
 
 impl GeographicDistanceEvaluator for PythagorasDistanceEvaluator {
-  fn determine_distance(
-    &self,
-    location1: &GeographicAreaDto,
-    location2: &GeographicLocationDto,
-  ) -> Result<f64, ParseFloatError> {
-    let lat_diff =
-      self.lat_to_km(location1.latitude.parse::<f64>()? - location2.latitude.parse::<f64>()?);
+  fn determine_distance(&self, location1: &GeoPoint, location2: &GeoPoint) -> f64 {
+    let lat_diff = self.lat_to_km(location1.latitude() - location2.latitude());
     let long_diff = self.long_to_km(
-      (location1.latitude.parse::<f64>()? - location2.latitude.parse::<f64>()?).abs() / 2.0,
-      location1.longitude.parse::<f64>()? - location2.longitude.parse::<f64>()?,
+      (location1.latitude() + location2.latitude()) / 2.0,
+      location1.longitude() - location2.longitude(),
     );
 
-    Ok((lat_diff.powi(2) + long_diff.powi(2)).sqrt())
+    (lat_diff.powi(2) + long_diff.powi(2)).sqrt()
+  }
+}
+
+pub struct HaversineDistanceEvaluator {}
+
+impl HaversineDistanceEvaluator {
+  pub fn new() -> HaversineDistanceEvaluator {
+    HaversineDistanceEvaluator {}
+  }
+}
+
+impl GeographicDistanceEvaluator for HaversineDistanceEvaluator {
+  fn determine_distance(&self, location1: &GeoPoint, location2: &GeoPoint) -> f64 {
+    let lat1 = location1.latitude().to_radians();
+    let lat2 = location2.latitude().to_radians();
+    let lat_diff = lat2 - lat1;
+    let long_diff = (location2.longitude() - location1.longitude()).to_radians();
+
+    let a =
+      (lat_diff / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (long_diff / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().min(1.0).asin()
+  }
+}
+
+pub struct VincentyDistanceEvaluator {
+  fallback: HaversineDistanceEvaluator,
+}
+
+impl VincentyDistanceEvaluator {
+  pub fn new() -> VincentyDistanceEvaluator {
+    VincentyDistanceEvaluator {
+      fallback: HaversineDistanceEvaluator::new(),
+    }
+  }
+}
+
+impl GeographicDistanceEvaluator for VincentyDistanceEvaluator {
+  fn determine_distance(&self, location1: &GeoPoint, location2: &GeoPoint) -> f64 {
+    let b = (1.0 - WGS84_F) * WGS84_A;
+    let l = (location2.longitude() - location1.longitude()).to_radians();
+    let u1 = ((1.0 - WGS84_F) * location1.latitude().to_radians().tan()).atan();
+    let u2 = ((1.0 - WGS84_F) * location2.latitude().to_radians().tan()).atan();
+    let (sin_u1, cos_u1) = u1.sin_cos();
+    let (sin_u2, cos_u2) = u2.sin_cos();
+
+    let mut lambda = l;
+    for _ in 0..VINCENTY_MAX_ITERATIONS {
+      let (sin_lambda, cos_lambda) = lambda.sin_cos();
+      let sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+        + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+      .sqrt();
+      if sin_sigma == 0.0 {
+        return 0.0;
+      }
+      let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+      let sigma = sin_sigma.atan2(cos_sigma);
+      let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+      let cos_sq_alpha = 1.0 - sin_alpha.powi(2);
+      let cos_2sigma_m = if cos_sq_alpha == 0.0 {
+        0.0
+      } else {
+        cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha
+      };
+      let c = WGS84_F / 16.0 * cos_sq_alpha * (4.0 + WGS84_F * (4.0 - 3.0 * cos_sq_alpha));
+      let previous_lambda = lambda;
+      lambda = l
+        + (1.0 - c)
+          * WGS84_F
+          * sin_alpha
+          * (sigma
+            + c * sin_sigma * (cos_2sigma_m + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2))));
+
+      if (lambda - previous_lambda).abs() < VINCENTY_TOLERANCE {
+        let u_sq = cos_sq_alpha * (WGS84_A.powi(2) - b.powi(2)) / b.powi(2);
+        let big_a =
+          1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+        let big_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+        let delta_sigma = big_b
+          * sin_sigma
+          * (cos_2sigma_m
+            + big_b / 4.0
+              * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m.powi(2))
+                - big_b / 6.0
+                  * cos_2sigma_m
+                  * (-3.0 + 4.0 * sin_sigma.powi(2))
+                  * (-3.0 + 4.0 * cos_2sigma_m.powi(2))));
+
+        return b * big_a * (sigma - delta_sigma) / 1000.0;
+      }
+    }
+
+    log::debug!("Vincenty formula did not converge; falling back to haversine distance");
+    self.fallback.determine_distance(location1, location2)
   }
 }
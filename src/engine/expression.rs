@@ -0,0 +1,672 @@
+use crate::domain::job::Job;
+use crate::domain::skills::normalize;
+use crate::domain::worker::Worker;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ValueType {
+  Number,
+  Bool,
+  Text,
+  TextList,
+}
+
+impl fmt::Display for ValueType {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let name = match self {
+      ValueType::Number => "number",
+      ValueType::Bool => "boolean",
+      ValueType::Text => "text",
+      ValueType::TextList => "list",
+    };
+    write!(f, "{}", name)
+  }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+  Number(f64),
+  Bool(bool),
+  Text(String),
+  TextList(Vec<String>),
+}
+
+impl Value {
+  fn as_number(&self) -> f64 {
+    match self {
+      Value::Number(n) => *n,
+      _ => 0.0,
+    }
+  }
+
+  fn as_bool(&self) -> bool {
+    match self {
+      Value::Bool(b) => *b,
+      _ => false,
+    }
+  }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ExpressionError {
+  pub position: usize,
+  pub message: String,
+}
+
+impl ExpressionError {
+  fn new(position: usize, message: String) -> ExpressionError {
+    ExpressionError { position, message }
+  }
+}
+
+impl fmt::Display for ExpressionError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "at column {}: {}", self.position + 1, self.message)
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Field {
+  WorkerRating,
+  WorkerAge,
+  WorkerActive,
+  WorkerHasDriversLicense,
+  WorkerTransportation,
+  WorkerMaxJobDistance,
+  WorkerCertificates,
+  WorkerSkills,
+  JobCompany,
+  JobBillRate,
+  JobWorkersRequired,
+  JobDriverLicenseRequired,
+  JobRequiredCertificates,
+  JobRequiredSkills,
+  JobPreferredSkills,
+}
+
+const FIELDS: [(&str, Field, ValueType); 15] = [
+  ("worker.rating", Field::WorkerRating, ValueType::Number),
+  ("worker.age", Field::WorkerAge, ValueType::Number),
+  ("worker.active", Field::WorkerActive, ValueType::Bool),
+  (
+    "worker.hasDriversLicense",
+    Field::WorkerHasDriversLicense,
+    ValueType::Bool,
+  ),
+  (
+    "worker.transportation",
+    Field::WorkerTransportation,
+    ValueType::Text,
+  ),
+  (
+    "worker.maxJobDistance",
+    Field::WorkerMaxJobDistance,
+    ValueType::Number,
+  ),
+  (
+    "worker.certificates",
+    Field::WorkerCertificates,
+    ValueType::TextList,
+  ),
+  ("worker.skills", Field::WorkerSkills, ValueType::TextList),
+  ("job.company", Field::JobCompany, ValueType::Text),
+  ("job.billRate", Field::JobBillRate, ValueType::Number),
+  (
+    "job.workersRequired",
+    Field::JobWorkersRequired,
+    ValueType::Number,
+  ),
+  (
+    "job.driverLicenseRequired",
+    Field::JobDriverLicenseRequired,
+    ValueType::Bool,
+  ),
+  (
+    "job.requiredCertificates",
+    Field::JobRequiredCertificates,
+    ValueType::TextList,
+  ),
+  (
+    "job.requiredSkills",
+    Field::JobRequiredSkills,
+    ValueType::TextList,
+  ),
+  (
+    "job.preferredSkills",
+    Field::JobPreferredSkills,
+    ValueType::TextList,
+  ),
+];
+
+impl Field {
+  fn lookup(name: &str) -> Option<(Field, ValueType)> {
+    FIELDS.iter().find(|f| f.0 == name).map(|f| (f.1, f.2))
+  }
+
+  fn read(self, worker: &Worker, job: &Job) -> Value {
+    match self {
+      Field::WorkerRating => Value::Number(worker.rating as f64),
+      Field::WorkerAge => Value::Number(worker.age as f64),
+      Field::WorkerActive => Value::Bool(worker.active),
+      Field::WorkerHasDriversLicense => Value::Bool(worker.has_drivers_license),
+      Field::WorkerTransportation => Value::Text(String::from(worker.transportation.label())),
+      Field::WorkerMaxJobDistance => Value::Number(
+        worker
+          .job_search_address
+          .max_job_distance_km()
+          .unwrap_or(f64::NAN),
+      ),
+      Field::WorkerCertificates => Value::TextList(worker.certificates.iter().cloned().collect()),
+      Field::WorkerSkills => Value::TextList(worker.skills.iter().cloned().collect()),
+      Field::JobCompany => Value::Text(job.company.clone()),
+      // An unknown bill rate reads as NaN, so every ordering comparison fails.
+      Field::JobBillRate => Value::Number(job.bill_rate.map_or(f64::NAN, |b| b.amount())),
+      Field::JobWorkersRequired => Value::Number(job.workers_required as f64),
+      Field::JobDriverLicenseRequired => Value::Bool(job.driver_license_required),
+      Field::JobRequiredCertificates => {
+        Value::TextList(job.required_certificates.iter().cloned().collect())
+      }
+      Field::JobRequiredSkills => Value::TextList(job.required_skills.iter().cloned().collect()),
+      Field::JobPreferredSkills => Value::TextList(job.preferred_skills.iter().cloned().collect()),
+    }
+  }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+  Number(f64),
+  Text(String),
+  Word(String),
+  Symbol(&'static str),
+  End,
+}
+
+impl fmt::Display for Token {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      Token::Number(n) => write!(f, "{}", n),
+      Token::Text(t) => write!(f, "\"{}\"", t),
+      Token::Word(w) => write!(f, "{}", w),
+      Token::Symbol(s) => write!(f, "{}", s),
+      Token::End => write!(f, "end of expression"),
+    }
+  }
+}
+
+const SYMBOLS: [&str; 12] = [
+  "==", "!=", "<=", ">=", "<", ">", "+", "-", "*", "/", "(", ")",
+];
+
+fn tokenize(source: &str) -> Result<Vec<(Token, usize)>, ExpressionError> {
+  let chars: Vec<char> = source.chars().collect();
+  let mut tokens: Vec<(Token, usize)> = Vec::new();
+  let mut i = 0;
+  while i < chars.len() {
+    let c = chars[i];
+    if c.is_whitespace() {
+      i += 1;
+    } else if c.is_ascii_digit() {
+      let start = i;
+      while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+        i += 1;
+      }
+      let text: String = chars[start..i].iter().collect();
+      let number = text
+        .parse()
+        .map_err(|_| ExpressionError::new(start, format!("invalid number '{}'", text)))?;
+      tokens.push((Token::Number(number), start));
+    } else if c == '"' || c == '\'' {
+      let start = i;
+      i += 1;
+      while i < chars.len() && chars[i] != c {
+        i += 1;
+      }
+      if i == chars.len() {
+        return Err(ExpressionError::new(
+          start,
+          String::from("unterminated string"),
+        ));
+      }
+      tokens.push((Token::Text(chars[start + 1..i].iter().collect()), start));
+      i += 1;
+    } else if c.is_alphabetic() || c == '_' {
+      let start = i;
+      while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '.') {
+        i += 1;
+      }
+      tokens.push((Token::Word(chars[start..i].iter().collect()), start));
+    } else {
+      let rest: String = chars[i..chars.len().min(i + 2)].iter().collect();
+      match SYMBOLS.iter().find(|s| rest.starts_with(*s)) {
+        Some(symbol) => {
+          tokens.push((Token::Symbol(symbol), i));
+          i += symbol.len();
+        }
+        None => {
+          return Err(ExpressionError::new(
+            i,
+            format!("unexpected character '{}'", c),
+          ))
+        }
+      }
+    }
+  }
+  tokens.push((Token::End, chars.len()));
+
+  Ok(tokens)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum BinaryOp {
+  Or,
+  And,
+  Eq,
+  Ne,
+  Lt,
+  Le,
+  Gt,
+  Ge,
+  In,
+  Add,
+  Sub,
+  Mul,
+  Div,
+}
+
+#[derive(Debug)]
+enum Expr {
+  Literal(Value),
+  Field(Field),
+  Not(Box<Expr>),
+  Negate(Box<Expr>),
+  Binary(BinaryOp, Box<Expr>, Box<Expr>),
+  If(Box<Expr>, Box<Expr>, Box<Expr>),
+}
+
+struct Typed {
+  expr: Expr,
+  value_type: ValueType,
+  position: usize,
+}
+
+const MAX_DEPTH: usize = 64;
+
+struct Parser {
+  tokens: Vec<(Token, usize)>,
+  current: usize,
+  depth: usize,
+}
+
+impl Parser {
+  fn peek(&self) -> &Token {
+    &self.tokens[self.current].0
+  }
+
+  fn position(&self) -> usize {
+    self.tokens[self.current].1
+  }
+
+  fn advance(&mut self) -> (Token, usize) {
+    let token = self.tokens[self.current].clone();
+    if self.current < self.tokens.len() - 1 {
+      self.current += 1;
+    }
+    token
+  }
+
+  fn is_word(&self, word: &str) -> bool {
+    matches!(self.peek(), Token::Word(w) if w == word)
+  }
+
+  fn expect_word(&mut self, word: &str) -> Result<(), ExpressionError> {
+    if self.is_word(word) {
+      self.advance();
+      Ok(())
+    } else {
+      Err(ExpressionError::new(
+        self.position(),
+        format!("expected '{}' but found {}", word, self.peek()),
+      ))
+    }
+  }
+
+  fn expect_type(typed: &Typed, expected: ValueType, context: &str) -> Result<(), ExpressionError> {
+    if typed.value_type == expected {
+      Ok(())
+    } else {
+      Err(ExpressionError::new(
+        typed.position,
+        format!(
+          "{} expects {} but found {}",
+          context, expected, typed.value_type
+        ),
+      ))
+    }
+  }
+
+  fn binary(op: BinaryOp, left: Typed, right: Typed, value_type: ValueType) -> Typed {
+    Typed {
+      position: left.position,
+      expr: Expr::Binary(op, Box::new(left.expr), Box::new(right.expr)),
+      value_type,
+    }
+  }
+
+  fn nested(
+    &mut self,
+    parse: fn(&mut Parser) -> Result<Typed, ExpressionError>,
+  ) -> Result<Typed, ExpressionError> {
+    if self.depth >= MAX_DEPTH {
+      return Err(ExpressionError::new(
+        self.position(),
+        format!("expression is nested more than {} levels deep", MAX_DEPTH),
+      ));
+    }
+    self.depth += 1;
+    let result = parse(self);
+    self.depth -= 1;
+    result
+  }
+
+  fn expression(&mut self) -> Result<Typed, ExpressionError> {
+    self.nested(Parser::conditional)
+  }
+
+  fn conditional(&mut self) -> Result<Typed, ExpressionError> {
+    if self.is_word("if") {
+      let position = self.advance().1;
+      let condition = self.expression()?;
+      Parser::expect_type(&condition, ValueType::Bool, "'if'")?;
+      self.expect_word("then")?;
+      let then_branch = self.expression()?;
+      self.expect_word("else")?;
+      let else_branch = self.expression()?;
+      if then_branch.value_type != else_branch.value_type {
+        return Err(ExpressionError::new(
+          else_branch.position,
+          format!(
+            "'else' branch is {} but 'then' branch is {}",
+            else_branch.value_type, then_branch.value_type
+          ),
+        ));
+      }
+      return Ok(Typed {
+        value_type: then_branch.value_type,
+        expr: Expr::If(
+          Box::new(condition.expr),
+          Box::new(then_branch.expr),
+          Box::new(else_branch.expr),
+        ),
+        position,
+      });
+    }
+    self.or()
+  }
+
+  fn or(&mut self) -> Result<Typed, ExpressionError> {
+    let mut left = self.and()?;
+    while self.is_word("or") {
+      self.advance();
+      let right = self.and()?;
+      Parser::expect_type(&left, ValueType::Bool, "'or'")?;
+      Parser::expect_type(&right, ValueType::Bool, "'or'")?;
+      left = Parser::binary(BinaryOp::Or, left, right, ValueType::Bool);
+    }
+    Ok(left)
+  }
+
+  fn and(&mut self) -> Result<Typed, ExpressionError> {
+    let mut left = self.not()?;
+    while self.is_word("and") {
+      self.advance();
+      let right = self.not()?;
+      Parser::expect_type(&left, ValueType::Bool, "'and'")?;
+      Parser::expect_type(&right, ValueType::Bool, "'and'")?;
+      left = Parser::binary(BinaryOp::And, left, right, ValueType::Bool);
+    }
+    Ok(left)
+  }
+
+  fn not(&mut self) -> Result<Typed, ExpressionError> {
+    if self.is_word("not") {
+      let position = self.advance().1;
+      let operand = self.nested(Parser::not)?;
+      Parser::expect_type(&operand, ValueType::Bool, "'not'")?;
+      return Ok(Typed {
+        expr: Expr::Not(Box::new(operand.expr)),
+        value_type: ValueType::Bool,
+        position,
+      });
+    }
+    self.comparison()
+  }
+
+  fn comparison(&mut self) -> Result<Typed, ExpressionError> {
+    let left = self.sum()?;
+    let op = match self.peek() {
+      Token::Symbol("==") => BinaryOp::Eq,
+      Token::Symbol("!=") => BinaryOp::Ne,
+      Token::Symbol("<") => BinaryOp::Lt,
+      Token::Symbol("<=") => BinaryOp::Le,
+      Token::Symbol(">") => BinaryOp::Gt,
+      Token::Symbol(">=") => BinaryOp::Ge,
+      Token::Word(w) if w == "in" => BinaryOp::In,
+      _ => return Ok(left),
+    };
+    let (operator, _) = self.advance();
+    let context = format!("'{}'", operator);
+    let right = self.sum()?;
+    match op {
+      BinaryOp::Eq | BinaryOp::Ne => {
+        if left.value_type == ValueType::TextList {
+          return Err(ExpressionError::new(
+            left.position,
+            format!("{} cannot compare lists", context),
+          ));
+        }
+        Parser::expect_type(&right, left.value_type, &context)?;
+      }
+      BinaryOp::In => {
+        Parser::expect_type(&left, ValueType::Text, &context)?;
+        Parser::expect_type(&right, ValueType::TextList, &context)?;
+      }
+      _ => {
+        Parser::expect_type(&left, ValueType::Number, &context)?;
+        Parser::expect_type(&right, ValueType::Number, &context)?;
+      }
+    }
+    Ok(Parser::binary(op, left, right, ValueType::Bool))
+  }
+
+  fn sum(&mut self) -> Result<Typed, ExpressionError> {
+    let mut left = self.product()?;
+    loop {
+      let op = match self.peek() {
+        Token::Symbol("+") => BinaryOp::Add,
+        Token::Symbol("-") => BinaryOp::Sub,
+        _ => return Ok(left),
+      };
+      let (operator, _) = self.advance();
+      let right = self.product()?;
+      let context = format!("'{}'", operator);
+      Parser::expect_type(&left, ValueType::Number, &context)?;
+      Parser::expect_type(&right, ValueType::Number, &context)?;
+      left = Parser::binary(op, left, right, ValueType::Number);
+    }
+  }
+
+  fn product(&mut self) -> Result<Typed, ExpressionError> {
+    let mut left = self.unary()?;
+    loop {
+      let op = match self.peek() {
+        Token::Symbol("*") => BinaryOp::Mul,
+        Token::Symbol("/") => BinaryOp::Div,
+        _ => return Ok(left),
+      };
+      let (operator, _) = self.advance();
+      let right = self.unary()?;
+      let context = format!("'{}'", operator);
+      Parser::expect_type(&left, ValueType::Number, &context)?;
+      Parser::expect_type(&right, ValueType::Number, &context)?;
+      left = Parser::binary(op, left, right, ValueType::Number);
+    }
+  }
+
+  fn unary(&mut self) -> Result<Typed, ExpressionError> {
+    if self.peek() == &Token::Symbol("-") {
+      let position = self.advance().1;
+      let operand = self.nested(Parser::unary)?;
+      Parser::expect_type(&operand, ValueType::Number, "'-'")?;
+      return Ok(Typed {
+        expr: Expr::Negate(Box::new(operand.expr)),
+        value_type: ValueType::Number,
+        position,
+      });
+    }
+    self.primary()
+  }
+
+  fn primary(&mut self) -> Result<Typed, ExpressionError> {
+    if self.is_word("if") {
+      return self.expression();
+    }
+    let (token, position) = self.advance();
+    let (expr, value_type) = match token {
+      Token::Number(n) => (Expr::Literal(Value::Number(n)), ValueType::Number),
+      Token::Text(t) => (Expr::Literal(Value::Text(t)), ValueType::Text),
+      Token::Word(w) if w == "true" || w == "false" => {
+        (Expr::Literal(Value::Bool(w == "true")), ValueType::Bool)
+      }
+      Token::Word(w) => match Field::lookup(&w) {
+        Some((field, value_type)) => (Expr::Field(field), value_type),
+        None => {
+          return Err(ExpressionError::new(
+            position,
+            format!("unknown field '{}'", w),
+          ))
+        }
+      },
+      Token::Symbol("(") => {
+        let inner = self.expression()?;
+        if self.peek() != &Token::Symbol(")") {
+          return Err(ExpressionError::new(
+            self.position(),
+            format!("expected ')' but found {}", self.peek()),
+          ));
+        }
+        self.advance();
+        return Ok(Typed { position, ..inner });
+      }
+      other => {
+        return Err(ExpressionError::new(
+          position,
+          format!("unexpected {}", other),
+        ))
+      }
+    };
+    Ok(Typed {
+      expr,
+      value_type,
+      position,
+    })
+  }
+}
+
+/// Text compares the way `in` does, so `worker.transportation == "car"` matches `CAR`.
+fn equals(left: &Value, right: &Value) -> bool {
+  match (left, right) {
+    (Value::Text(left), Value::Text(right)) => normalize(left) == normalize(right),
+    _ => left == right,
+  }
+}
+
+fn evaluate(expr: &Expr, worker: &Worker, job: &Job) -> Value {
+  match expr {
+    Expr::Literal(value) => value.clone(),
+    Expr::Field(field) => field.read(worker, job),
+    Expr::Not(operand) => Value::Bool(!evaluate(operand, worker, job).as_bool()),
+    Expr::Negate(operand) => Value::Number(-evaluate(operand, worker, job).as_number()),
+    Expr::If(condition, then_branch, else_branch) => {
+      if evaluate(condition, worker, job).as_bool() {
+        evaluate(then_branch, worker, job)
+      } else {
+        evaluate(else_branch, worker, job)
+      }
+    }
+    Expr::Binary(BinaryOp::Or, left, right) => {
+      Value::Bool(evaluate(left, worker, job).as_bool() || evaluate(right, worker, job).as_bool())
+    }
+    Expr::Binary(BinaryOp::And, left, right) => {
+      Value::Bool(evaluate(left, worker, job).as_bool() && evaluate(right, worker, job).as_bool())
+    }
+    Expr::Binary(op, left, right) => {
+      let left = evaluate(left, worker, job);
+      let right = evaluate(right, worker, job);
+      match op {
+        BinaryOp::Eq => Value::Bool(equals(&left, &right)),
+        BinaryOp::Ne => Value::Bool(!equals(&left, &right)),
+        BinaryOp::Lt => Value::Bool(left.as_number() < right.as_number()),
+        BinaryOp::Le => Value::Bool(left.as_number() <= right.as_number()),
+        BinaryOp::Gt => Value::Bool(left.as_number() > right.as_number()),
+        BinaryOp::Ge => Value::Bool(left.as_number() >= right.as_number()),
+        BinaryOp::Add => Value::Number(left.as_number() + right.as_number()),
+        BinaryOp::Sub => Value::Number(left.as_number() - right.as_number()),
+        BinaryOp::Mul => Value::Number(left.as_number() * right.as_number()),
+        BinaryOp::Div => Value::Number(left.as_number() / right.as_number()),
+        BinaryOp::In => match (left, right) {
+          (Value::Text(item), Value::TextList(list)) => {
+            let item = normalize(&item);
+            Value::Bool(list.iter().any(|i| normalize(i) == item))
+          }
+          _ => Value::Bool(false),
+        },
+        BinaryOp::Or | BinaryOp::And => unreachable!(),
+      }
+    }
+  }
+}
+
+pub struct Expression {
+  source: String,
+  expr: Expr,
+  value_type: ValueType,
+}
+
+impl Expression {
+  pub fn compile(source: &str) -> Result<Expression, ExpressionError> {
+    let mut parser = Parser {
+      tokens: tokenize(source)?,
+      current: 0,
+      depth: 0,
+    };
+    let typed = parser.expression()?;
+    if parser.peek() != &Token::End {
+      return Err(ExpressionError::new(
+        parser.position(),
+        format!("unexpected {}", parser.peek()),
+      ));
+    }
+
+    Ok(Expression {
+      source: String::from(source),
+      expr: typed.expr,
+      value_type: typed.value_type,
+    })
+  }
+
+  pub fn source(&self) -> &str {
+    &self.source
+  }
+
+  pub fn value_type(&self) -> ValueType {
+    self.value_type
+  }
+
+  pub fn evaluate_bool(&self, worker: &Worker, job: &Job) -> bool {
+    evaluate(&self.expr, worker, job).as_bool()
+  }
+
+  pub fn evaluate_number(&self, worker: &Worker, job: &Job) -> f64 {
+    evaluate(&self.expr, worker, job).as_number()
+  }
+}
@@ -0,0 +1,89 @@
+use super::config::EvaluationContext;
+use super::curve::ScoringCurve;
+use super::expression::Expression;
+use super::match_rating::{MatchRating, RatingOutcome, RatingResult, RejectionReason};
+use std::collections::HashMap;
+
+pub struct ExpressionRule {
+  name: String,
+  weight_value: f64,
+  filter: Option<Expression>,
+  score: Option<Expression>,
+  curve: Option<ScoringCurve>,
+}
+
+impl ExpressionRule {
+  pub fn new(
+    name: String,
+    weight: f64,
+    filter: Option<Expression>,
+    score: Option<Expression>,
+    curve: Option<ScoringCurve>,
+  ) -> ExpressionRule {
+    ExpressionRule {
+      name,
+      weight_value: weight,
+      filter,
+      score,
+      curve,
+    }
+  }
+}
+
+impl MatchRating for ExpressionRule {
+  fn get_name(&self) -> &str {
+    &self.name
+  }
+
+  fn get_weight(&self) -> f64 {
+    self.weight_value
+  }
+
+  fn determine_rating(&self, ctx: &EvaluationContext) -> RatingResult {
+    log::debug!(
+      "Running rule {} for Worker {} and Job {}",
+      self.get_name(),
+      ctx.worker.user_id,
+      ctx.job.job_id
+    );
+    let mut metrics: HashMap<String, f64> = HashMap::new();
+    if let Some(filter) = &self.filter {
+      let passed = filter.evaluate_bool(ctx.worker, ctx.job);
+      if ctx.config.with_diagnosis {
+        metrics.insert(String::from("filterPassed"), if passed { 1.0 } else { 0.0 });
+      }
+      if !passed {
+        log::debug!(
+          "Filter {} failed; {} rule failure",
+          filter.source(),
+          self.get_name()
+        );
+        return RatingResult {
+          outcome: RatingOutcome::Reject(RejectionReason::FailedFilter(String::from(
+            filter.source(),
+          ))),
+          metrics,
+        };
+      }
+    }
+
+    let score = match &self.score {
+      Some(score) => {
+        let value = score.evaluate_number(ctx.worker, ctx.job);
+        let value = if value.is_finite() { value } else { 0.0 };
+        let transformed = self.curve.as_ref().map_or(value, |c| c.apply(value));
+        if ctx.config.with_diagnosis {
+          metrics.insert(String::from("rawValue"), value);
+          metrics.insert(String::from("transformedValue"), transformed);
+        }
+        transformed
+      }
+      None => 0.0,
+    };
+    log::debug!("Rule {} completed with score {}", self.get_name(), score);
+    RatingResult {
+      outcome: RatingOutcome::Pass(score),
+      metrics,
+    }
+  }
+}
@@ -1,21 +1,25 @@
 use super::config::EvaluationContext;
+use super::curve::ScoringCurve;
 use super::distance::GeographicDistanceEvaluator;
-use super::match_rating::{MatchRating, RatingResult};
+use super::match_rating::{MatchRating, RatingOutcome, RatingResult, RejectionReason};
 use std::collections::HashMap;
 
 pub struct JobLocation {
   weight_value: f64,
   distance_evaluator: Box<dyn GeographicDistanceEvaluator + Send + Sync>,
+  curve: Option<ScoringCurve>,
 }
 
 impl JobLocation {
   pub fn new(
     weight: f64,
     distance_evaluator: Box<dyn GeographicDistanceEvaluator + Send + Sync>,
+    curve: Option<ScoringCurve>,
   ) -> JobLocation {
     JobLocation {
       weight_value: weight,
       distance_evaluator,
+      curve,
     }
   }
 }
@@ -36,50 +40,74 @@ impl MatchRating for JobLocation {
       ctx.worker.user_id,
       ctx.job.job_id
     );
-    if ctx.worker.job_search_address.unit != "km" {
-      log::error!(
-        "Worker {} has a job search location using '{}'; we only support 'km' at the moment",
-        ctx.worker.user_id,
-        ctx.worker.job_search_address.unit
-      );
-      return RatingResult {
-        rating: -1.0,
-        metrics: HashMap::new(),
-      };
-    }
-
+    let search_area = &ctx.worker.job_search_address;
+    let (worker_location, job_location) = match (search_area.location, ctx.job.location) {
+      (Some(w), Some(j)) => (w, j),
+      (w, j) => {
+        log::debug!("Worker or job location is unknown");
+        return RatingResult {
+          outcome: RatingOutcome::Reject(RejectionReason::UnknownLocation {
+            worker: w.is_none(),
+            job: j.is_none(),
+          }),
+          metrics: HashMap::new(),
+        };
+      }
+    };
+    let unit = match search_area.unit {
+      Some(unit) => unit,
+      None => {
+        log::debug!("Worker search distance unit is unknown");
+        return RatingResult {
+          outcome: RatingOutcome::Reject(RejectionReason::UnknownDistanceUnit(
+            ctx.worker.source.job_search_address.unit.clone(),
+          )),
+          metrics: HashMap::new(),
+        };
+      }
+    };
     let distance = self
       .distance_evaluator
-      .determine_distance(&ctx.worker.job_search_address, &ctx.job.location);
-    if let Err(e) = distance {
-      log::error!("Error when trying to calculate job site distance {:?}", e);
-      return RatingResult {
-        rating: -1.0,
-        metrics: HashMap::new(),
-      };
-    }
-    let distance = distance.unwrap();
+      .determine_distance(&worker_location, &job_location);
+    let max_job_distance = unit.convert_to_km(search_area.max_job_distance);
 
     let mut metrics: HashMap<String, f64> = HashMap::new();
     if ctx.config.with_diagnosis {
       metrics.insert(String::from("distance"), distance);
+      metrics.insert(String::from("maxJobDistance"), max_job_distance);
       metrics.insert(
-        String::from("maxJobDistance"),
-        ctx.worker.job_search_address.max_job_distance,
+        String::from("originalDistance"),
+        unit.convert_from_km(distance),
+      );
+      metrics.insert(
+        String::from("originalMaxJobDistance"),
+        search_area.max_job_distance,
       );
     }
-    if distance > ctx.worker.job_search_address.max_job_distance {
+    if distance > max_job_distance {
       log::debug!("Job location is too far away");
       RatingResult {
-        rating: -1.0,
+        outcome: RatingOutcome::Reject(RejectionReason::OutsideSearchArea {
+          distance,
+          max_job_distance,
+        }),
         metrics,
       }
     } else {
       log::debug!("Job location is within search distance");
-      let rating = self.get_weight() * (ctx.worker.job_search_address.max_job_distance - distance)
-        / ctx.worker.job_search_address.max_job_distance;
-      log::debug!("Rule {} completed with rating {}", self.get_name(), rating);
-      RatingResult { rating, metrics }
+      let score = match &self.curve {
+        Some(curve) => curve.apply(distance),
+        None => (max_job_distance - distance) / max_job_distance,
+      };
+      if ctx.config.with_diagnosis {
+        metrics.insert(String::from("rawValue"), distance);
+        metrics.insert(String::from("transformedValue"), score);
+      }
+      log::debug!("Rule {} completed with score {}", self.get_name(), score);
+      RatingResult {
+        outcome: RatingOutcome::Pass(score),
+        metrics,
+      }
     }
   }
 }
@@ -1,15 +1,23 @@
 use super::config::EvaluationContext;
-use super::match_rating::{MatchRating, RatingResult};
+use super::curve::ScoringCurve;
+use super::match_rating::{MatchRating, RatingOutcome, RatingResult};
 use std::collections::HashMap;
 
+const DEFAULT_MAX_WORKERS_REQUIRED: f64 = 10.0;
+
 pub struct JobPositions {
   weight_value: f64,
+  curve: ScoringCurve,
 }
 
 impl JobPositions {
-  pub fn new(weight: f64) -> JobPositions {
+  pub fn new(weight: f64, curve: Option<ScoringCurve>) -> JobPositions {
     JobPositions {
       weight_value: weight,
+      curve: curve.unwrap_or(ScoringCurve::Linear {
+        zero: 0.0,
+        one: DEFAULT_MAX_WORKERS_REQUIRED,
+      }),
     }
   }
 }
@@ -30,16 +38,19 @@ impl MatchRating for JobPositions {
       ctx.worker.user_id,
       ctx.job.job_id
     );
+    let workers_required = ctx.job.workers_required as f64;
+    let score = self.curve.apply(workers_required);
     let mut metrics: HashMap<String, f64> = HashMap::new();
     if ctx.config.with_diagnosis {
-      metrics.insert(
-        String::from("workersRequired"),
-        ctx.job.workers_required as f64,
-      );
+      metrics.insert(String::from("workersRequired"), workers_required);
+      metrics.insert(String::from("rawValue"), workers_required);
+      metrics.insert(String::from("transformedValue"), score);
     }
 
-    let rating = self.weight_value * ctx.job.workers_required as f64;
-    log::debug!("Rule {} completed with rating {}", self.get_name(), rating);
-    RatingResult { rating, metrics }
+    log::debug!("Rule {} completed with score {}", self.get_name(), score);
+    RatingResult {
+      outcome: RatingOutcome::Pass(score),
+      metrics,
+    }
   }
 }
@@ -1,8 +1,132 @@
 use std::collections::HashMap;
+use std::fmt;
+use chrono::{Datelike, NaiveDate};
 use super::config::EvaluationContext;
 
+#[derive(Clone, Debug, PartialEq)]
+pub enum RejectionReason {
+  MissingCertificates(Vec<String>),
+  OutsideSearchArea { distance: f64, max_job_distance: f64 },
+  UnknownLocation { worker: bool, job: bool },
+  UnknownDistanceUnit(String),
+  DriversLicenseRequired,
+  MissingRequiredSkills(Vec<String>),
+  RatingBelowMinimum { rating: u32, minimum_rating: u32 },
+  CommuteTooLong { commute_minutes: f64, max_commute_minutes: f64 },
+  FailedFilter(String),
+  InsufficientCoverage { coverage: f64, minimum_coverage: f64 },
+  InsufficientShiftOverlap { overlap_minutes: i64, shift_minutes: i64 },
+  BlackoutDate { start: NaiveDate, end: NaiveDate },
+}
+
+impl RejectionReason {
+  pub fn code(&self) -> &'static str {
+    match self {
+      RejectionReason::MissingCertificates(_) => "MISSING_CERTIFICATES",
+      RejectionReason::OutsideSearchArea { .. } => "OUTSIDE_SEARCH_AREA",
+      RejectionReason::UnknownLocation { .. } => "UNKNOWN_LOCATION",
+      RejectionReason::UnknownDistanceUnit(_) => "UNKNOWN_DISTANCE_UNIT",
+      RejectionReason::DriversLicenseRequired => "DRIVERS_LICENSE_REQUIRED",
+      RejectionReason::MissingRequiredSkills(_) => "MISSING_REQUIRED_SKILLS",
+      RejectionReason::RatingBelowMinimum { .. } => "RATING_BELOW_MINIMUM",
+      RejectionReason::CommuteTooLong { .. } => "COMMUTE_TOO_LONG",
+      RejectionReason::FailedFilter(_) => "FAILED_FILTER",
+      RejectionReason::InsufficientCoverage { .. } => "INSUFFICIENT_COVERAGE",
+      RejectionReason::InsufficientShiftOverlap { .. } => "INSUFFICIENT_SHIFT_OVERLAP",
+      RejectionReason::BlackoutDate { .. } => "BLACKOUT_DATE",
+    }
+  }
+}
+
+impl fmt::Display for RejectionReason {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      RejectionReason::MissingCertificates(certificates) => {
+        write!(f, "Missing certificates: {}", certificates.join(", "))
+      }
+      RejectionReason::OutsideSearchArea {
+        distance,
+        max_job_distance,
+      } => write!(
+        f,
+        "Job is {:.1}km away; maximum is {:.1}km",
+        distance, max_job_distance
+      ),
+      RejectionReason::UnknownLocation { worker, job } => match (worker, job) {
+        (true, true) => write!(f, "Worker and job locations are unknown"),
+        (true, false) => write!(f, "Worker location is unknown"),
+        _ => write!(f, "Job location is unknown"),
+      },
+      RejectionReason::UnknownDistanceUnit(unit) => {
+        write!(f, "Worker search distance unit '{}' is unknown", unit)
+      }
+      RejectionReason::DriversLicenseRequired => write!(f, "Job requires a drivers license"),
+      RejectionReason::MissingRequiredSkills(skills) => {
+        write!(f, "Missing required skills: {}", skills.join(", "))
+      }
+      RejectionReason::RatingBelowMinimum {
+        rating,
+        minimum_rating,
+      } => write!(
+        f,
+        "Worker rating {} is below the minimum of {}",
+        rating, minimum_rating
+      ),
+      RejectionReason::CommuteTooLong {
+        commute_minutes,
+        max_commute_minutes,
+      } => write!(
+        f,
+        "Commute of {:.0} minutes exceeds the maximum of {:.0}",
+        commute_minutes, max_commute_minutes
+      ),
+      RejectionReason::FailedFilter(expression) => write!(f, "Failed filter: {}", expression),
+      RejectionReason::InsufficientCoverage {
+        coverage,
+        minimum_coverage,
+      } => write!(
+        f,
+        "Worker is available for {:.0}% of scheduled days; minimum is {:.0}%",
+        coverage * 100.0,
+        minimum_coverage * 100.0
+      ),
+      RejectionReason::InsufficientShiftOverlap {
+        overlap_minutes,
+        shift_minutes,
+      } => write!(
+        f,
+        "Worker is available for {} of the {} shift minutes",
+        overlap_minutes, shift_minutes
+      ),
+      RejectionReason::BlackoutDate { start, end } => {
+        write!(f, "Worker is unavailable from {} to {}", start, end)
+      }
+    }
+  }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RatingOutcome {
+  Pass(f64),
+  Reject(RejectionReason),
+  NotApplicable,
+}
+
+impl RatingOutcome {
+  pub fn score(&self) -> f64 {
+    match self {
+      RatingOutcome::Pass(score) => *score,
+      _ => 0.0,
+    }
+  }
+}
+
+pub fn date_metric(date: NaiveDate) -> f64 {
+  (date.year() * 10000 + date.month() as i32 * 100 + date.day() as i32) as f64
+}
+
 pub struct RatingResult {
-  pub rating: f64,
+  pub outcome: RatingOutcome,
   pub metrics: HashMap<String, f64>,
 }
 
@@ -1,9 +1,20 @@
 pub mod match_rating;
 pub mod available_on_start_day;
 pub mod required_certificates;
+pub mod schedule_coverage;
 pub mod config;
+pub mod curve;
 pub mod distance;
+pub mod expression;
+pub mod expression_rule;
 pub mod job_location;
 pub mod pay_rate;
 pub mod can_drive;
 pub mod job_positons;
+pub mod registry;
+pub mod skills;
+pub mod travel_time;
+pub mod worker_rating;
+
+#[cfg(test)]
+mod tests;
@@ -1,15 +1,23 @@
 use super::config::EvaluationContext;
-use super::match_rating::{MatchRating, RatingResult};
+use super::curve::ScoringCurve;
+use super::match_rating::{MatchRating, RatingOutcome, RatingResult};
 use std::collections::HashMap;
 
+const DEFAULT_MAX_BILL_RATE: f64 = 40.0;
+
 pub struct PayRate {
   weight_value: f64,
+  curve: ScoringCurve,
 }
 
 impl PayRate {
-  pub fn new(weight: f64) -> PayRate {
+  pub fn new(weight: f64, curve: Option<ScoringCurve>) -> PayRate {
     PayRate {
       weight_value: weight,
+      curve: curve.unwrap_or(ScoringCurve::Linear {
+        zero: 0.0,
+        one: DEFAULT_MAX_BILL_RATE,
+      }),
     }
   }
 }
@@ -30,24 +38,27 @@ impl MatchRating for PayRate {
       ctx.worker.user_id,
       ctx.job.job_id
     );
-    let rate = ctx.job.bill_rate.replace("$", "").parse::<f64>();
-    if let Err(e) = rate {
-      log::error!("Error parsing job.bill_rate {:?}", e);
-      return RatingResult {
-        rating: 0.0,
-        metrics: HashMap::new(),
-      };
-    }
-    let rate = rate.unwrap();
+    let rate = match ctx.job.bill_rate {
+      Some(rate) => rate.amount(),
+      None => {
+        log::debug!("Job bill rate is unknown");
+        return RatingResult {
+          outcome: RatingOutcome::NotApplicable,
+          metrics: HashMap::new(),
+        };
+      }
+    };
+    let score = self.curve.apply(rate);
 
     let mut metrics: HashMap<String, f64> = HashMap::new();
     if ctx.config.with_diagnosis {
       metrics.insert(String::from("billRate"), rate);
+      metrics.insert(String::from("rawValue"), rate);
+      metrics.insert(String::from("transformedValue"), score);
     }
-    let rating = self.get_weight() * rate;
-    log::debug!("Completed {} with rating {}", self.get_name(), rating);
+    log::debug!("Completed {} with score {}", self.get_name(), score);
     RatingResult {
-      rating,
+      outcome: RatingOutcome::Pass(score),
       metrics,
     }
   }
@@ -0,0 +1,339 @@
+use super::available_on_start_day::AvailableOnStartDay;
+use super::can_drive::CanDrive;
+use super::curve::ScoringCurve;
+use super::distance::{
+  GeographicDistanceEvaluator, HaversineDistanceEvaluator, PythagorasDistanceEvaluator,
+  VincentyDistanceEvaluator,
+};
+use super::expression::{Expression, ValueType};
+use super::expression_rule::ExpressionRule;
+use super::job_location::JobLocation;
+use super::job_positons::JobPositions;
+use super::match_rating::MatchRating;
+use super::pay_rate::PayRate;
+use super::required_certificates::{HasRequiredCertificates, DEFAULT_RATING_INCREMENT};
+use super::schedule_coverage::ScheduleCoverage;
+use super::skills::SkillsMatch;
+use super::travel_time::TravelTime;
+use super::worker_rating::WorkerRating;
+use crate::domain::config::{Config, DistanceEvaluatorType, RuleConfig};
+use crate::domain::timezone::TimezoneResolver;
+use crate::domain::transport::TransportMode;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+pub const RULE_NAMES: [&str; 10] = [
+  "AvailableOnStartDay",
+  "HasRequiredCertificates",
+  "JobLocation",
+  "PayRate",
+  "CanDrive",
+  "JobPositions",
+  "SkillsMatch",
+  "WorkerRating",
+  "TravelTime",
+  "ScheduleCoverage",
+];
+
+pub fn build_distance_evaluator(
+  evaluator_type: &DistanceEvaluatorType,
+) -> Box<dyn GeographicDistanceEvaluator + Send + Sync> {
+  match evaluator_type {
+    DistanceEvaluatorType::Pythagoras => Box::new(PythagorasDistanceEvaluator::new()),
+    DistanceEvaluatorType::Haversine => Box::new(HaversineDistanceEvaluator::new()),
+    DistanceEvaluatorType::Vincenty => Box::new(VincentyDistanceEvaluator::new()),
+  }
+}
+
+struct RuleEntry<'a> {
+  position: usize,
+  rule: &'a RuleConfig,
+  errors: Vec<String>,
+}
+
+impl RuleEntry<'_> {
+  fn error(&mut self, message: String) {
+    self.errors.push(format!(
+      "rules[{}] ({}): {}",
+      self.position, self.rule.name, message
+    ));
+  }
+
+  fn check_params(&mut self, allowed: &[&str]) {
+    let mut unknown: Vec<&String> = self
+      .rule
+      .params
+      .keys()
+      .filter(|k| !allowed.contains(&k.as_str()))
+      .collect();
+    unknown.sort();
+    for key in unknown {
+      self.error(format!("unknown parameter '{}'", key));
+    }
+  }
+
+  fn weight(&mut self) -> f64 {
+    let weight = self.rule.weight;
+    if !weight.is_finite() || weight < 0.0 {
+      self.error(format!(
+        "weight must be a non-negative number, got {}",
+        weight
+      ));
+    }
+    weight
+  }
+
+  fn expression(
+    &mut self,
+    kind: &str,
+    source: &Option<String>,
+    expected: ValueType,
+  ) -> Option<Expression> {
+    let expression = match Expression::compile(source.as_ref()?) {
+      Ok(expression) => expression,
+      Err(e) => {
+        self.error(format!("{} expression error {}", kind, e));
+        return None;
+      }
+    };
+    if expression.value_type() != expected {
+      self.error(format!(
+        "{} expression must be {} but is {}",
+        kind,
+        expected,
+        expression.value_type()
+      ));
+      return None;
+    }
+    Some(expression)
+  }
+
+  fn curve(&mut self) -> Option<ScoringCurve> {
+    let value = self.rule.params.get("curve")?;
+    let curve = match serde_json::from_value::<ScoringCurve>(value.clone()) {
+      Ok(curve) => curve,
+      Err(e) => {
+        self.error(format!("invalid curve: {}", e));
+        return None;
+      }
+    };
+    if let Err(e) = curve.validate() {
+      self.error(format!("invalid curve: {}", e));
+      return None;
+    }
+    Some(curve)
+  }
+
+  fn optional_number_param(&mut self, key: &str) -> Option<f64> {
+    let value = self.rule.params.get(key)?;
+    match value.as_f64() {
+      Some(n) if n.is_finite() && n > 0.0 => Some(n),
+      _ => {
+        self.error(format!(
+          "parameter '{}' must be a positive number, got {}",
+          key, value
+        ));
+        None
+      }
+    }
+  }
+
+  fn number_param(&mut self, key: &str, default: f64) -> f64 {
+    self.optional_number_param(key).unwrap_or(default)
+  }
+}
+
+fn build_expression_rule(entry: &mut RuleEntry) -> Option<Box<dyn MatchRating + Send + Sync>> {
+  if RULE_NAMES.contains(&entry.rule.name.as_str()) {
+    entry.error(String::from(
+      "expression rules cannot use the name of a built-in rule",
+    ));
+  }
+  entry.check_params(&["curve"]);
+  let weight = entry.weight();
+  let curve = entry.curve();
+  let filter = entry.expression("filter", &entry.rule.filter, ValueType::Bool);
+  let score = entry.expression("score", &entry.rule.score, ValueType::Number);
+  if entry.errors.is_empty() {
+    Some(Box::new(ExpressionRule::new(
+      entry.rule.name.clone(),
+      weight,
+      filter,
+      score,
+      curve,
+    )))
+  } else {
+    None
+  }
+}
+
+fn build_rule(
+  config: &Config,
+  timezones: &Arc<TimezoneResolver>,
+  entry: &mut RuleEntry,
+) -> Option<Box<dyn MatchRating + Send + Sync>> {
+  if entry.rule.filter.is_some() || entry.rule.score.is_some() {
+    return build_expression_rule(entry);
+  }
+  let rating: Box<dyn MatchRating + Send + Sync> = match entry.rule.name.as_str() {
+    "AvailableOnStartDay" => {
+      entry.check_params(&["min_shift_overlap"]);
+      let weight = entry.weight();
+      let minimum_shift_overlap = entry.optional_number_param("min_shift_overlap");
+      if minimum_shift_overlap.map_or(false, |m| m > 1.0) {
+        entry.error(String::from(
+          "parameter 'min_shift_overlap' must be between 0 and 1",
+        ));
+      }
+      Box::new(AvailableOnStartDay::new(
+        weight,
+        timezones.clone(),
+        minimum_shift_overlap,
+      ))
+    }
+    "HasRequiredCertificates" => {
+      entry.check_params(&["rating_increment"]);
+      let weight = entry.weight();
+      let rating_increment = entry.number_param("rating_increment", DEFAULT_RATING_INCREMENT);
+      if rating_increment >= 1.0 {
+        entry.error(format!(
+          "parameter 'rating_increment' must be below 1, got {}",
+          rating_increment
+        ));
+      }
+      Box::new(HasRequiredCertificates::new(weight, rating_increment))
+    }
+    "JobLocation" => {
+      entry.check_params(&["curve"]);
+      Box::new(JobLocation::new(
+        entry.weight(),
+        build_distance_evaluator(&config.distance_evaluator),
+        entry.curve(),
+      ))
+    }
+    "PayRate" => {
+      entry.check_params(&["curve"]);
+      Box::new(PayRate::new(entry.weight(), entry.curve()))
+    }
+    "CanDrive" => {
+      entry.check_params(&[]);
+      let weight = entry.weight();
+      if weight != 0.0 {
+        entry.error(format!(
+          "gate-only rule never adds to the rating; weight must be 0, got {}",
+          weight
+        ));
+      }
+      Box::new(CanDrive::new(weight))
+    }
+    "JobPositions" => {
+      entry.check_params(&["curve"]);
+      Box::new(JobPositions::new(entry.weight(), entry.curve()))
+    }
+    "SkillsMatch" => {
+      entry.check_params(&[]);
+      Box::new(SkillsMatch::new(entry.weight()))
+    }
+    "WorkerRating" => {
+      entry.check_params(&["curve"]);
+      Box::new(WorkerRating::new(
+        entry.weight(),
+        config.company_minimum_ratings.clone(),
+        entry.curve(),
+      ))
+    }
+    "TravelTime" => {
+      entry.check_params(&["curve"]);
+      let mut speeds: Vec<(&String, &f64)> = config.travel.speeds_kmh.iter().collect();
+      speeds.sort_by(|a, b| a.0.cmp(b.0));
+      for (mode, speed) in speeds {
+        if !speed.is_finite() || *speed <= 0.0 {
+          entry.error(format!(
+            "travel.speeds_kmh.{} must be a positive number, got {}",
+            mode, speed
+          ));
+        }
+      }
+      let default_speed = config.travel.default_speed_kmh;
+      if !default_speed.is_finite() || default_speed <= 0.0 {
+        entry.error(format!(
+          "travel.default_speed_kmh must be a positive number, got {}",
+          default_speed
+        ));
+      }
+      Box::new(TravelTime::new(
+        entry.weight(),
+        build_distance_evaluator(&config.distance_evaluator),
+        config
+          .travel
+          .speeds_kmh
+          .iter()
+          .map(|(mode, speed)| (TransportMode::parse(mode), *speed))
+          .collect(),
+        config.travel.default_speed_kmh,
+        config.travel.reference_commute_minutes,
+        entry.curve(),
+      ))
+    }
+    "ScheduleCoverage" => {
+      entry.check_params(&["min_coverage"]);
+      let weight = entry.weight();
+      let minimum_coverage = entry.optional_number_param("min_coverage");
+      if minimum_coverage.map_or(false, |m| m > 1.0) {
+        entry.error(String::from(
+          "parameter 'min_coverage' must be between 0 and 1",
+        ));
+      }
+      Box::new(ScheduleCoverage::new(weight, minimum_coverage))
+    }
+    _ => {
+      entry.error(format!(
+        "unknown rule; expected one of {}",
+        RULE_NAMES.join(", ")
+      ));
+      return None;
+    }
+  };
+
+  Some(rating)
+}
+
+pub fn build_match_ratings(
+  config: &Config,
+) -> Result<Vec<Box<dyn MatchRating + Send + Sync>>, Vec<String>> {
+  let mut errors: Vec<String> = Vec::new();
+  if config.rules.is_empty() {
+    errors.push(String::from("rules: at least one rule must be configured"));
+  }
+
+  let timezones = match TimezoneResolver::new(&config.timezones) {
+    Ok(timezones) => Arc::new(timezones),
+    Err(mut timezone_errors) => {
+      errors.append(&mut timezone_errors);
+      Arc::new(TimezoneResolver::new(&Default::default()).unwrap())
+    }
+  };
+
+  let mut seen: HashSet<&str> = HashSet::new();
+  let mut match_ratings: Vec<Box<dyn MatchRating + Send + Sync>> = Vec::new();
+  for (position, rule) in config.rules.iter().enumerate() {
+    let mut entry = RuleEntry {
+      position,
+      rule,
+      errors: Vec::new(),
+    };
+    if !seen.insert(rule.name.as_str()) {
+      entry.error(String::from("rule is configured more than once"));
+    }
+    if let Some(rating) = build_rule(config, &timezones, &mut entry) {
+      match_ratings.push(rating);
+    }
+    errors.append(&mut entry.errors);
+  }
+
+  if errors.is_empty() {
+    Ok(match_ratings)
+  } else {
+    Err(errors)
+  }
+}
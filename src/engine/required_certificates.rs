@@ -1,16 +1,20 @@
 use super::config::EvaluationContext;
-use super::match_rating::{MatchRating, RatingResult};
-use std::collections::{HashMap, HashSet};
+use super::match_rating::{MatchRating, RatingOutcome, RatingResult, RejectionReason};
+use std::collections::HashMap;
 
-const RATING_INCREMENT: f64 = 0.8;
+pub const DEFAULT_RATING_INCREMENT: f64 = 0.8;
 
 pub struct HasRequiredCertificates {
   weight: f64,
+  rating_increment: f64,
 }
 
 impl HasRequiredCertificates {
-  pub fn new(weight: f64) -> HasRequiredCertificates {
-    HasRequiredCertificates { weight }
+  pub fn new(weight: f64, rating_increment: f64) -> HasRequiredCertificates {
+    HasRequiredCertificates {
+      weight,
+      rating_increment,
+    }
   }
 }
 
@@ -30,46 +34,55 @@ impl MatchRating for HasRequiredCertificates {
       ctx.worker.user_id,
       ctx.job.job_id
     );
-    let worker_certificates: HashSet<&String> = ctx
-      .worker
-      .certificates
-      .iter()
-      .filter(|o| o.is_some())
-      .map(|o| o.as_ref().unwrap())
-      .collect();
+    if ctx.job.required_certificates.is_empty() {
+      log::debug!(
+        "Job requires no certificates; {} does not apply",
+        self.get_name()
+      );
+      return RatingResult {
+        outcome: RatingOutcome::NotApplicable,
+        metrics: HashMap::new(),
+      };
+    }
+
     let mut weighted_score: f64 = 0.0;
     let mut has_certs: i32 = 0;
-    let mut missing_certs: i32 = 0;
-    for required_cert in &ctx.job.required_certificates {
-      if worker_certificates.contains(required_cert) {
-        weighted_score += RATING_INCREMENT.powi(has_certs);
+    let mut missing_certs: Vec<String> = Vec::new();
+    for required_cert in ctx.job.required_certificates.iter() {
+      if ctx.worker.certificates.contains(required_cert) {
+        weighted_score += self.rating_increment.powi(has_certs);
         has_certs += 1;
       } else {
         if ctx.config.short_circuit_failures {
           log::debug!("{} Short-circuiting rule failure", self.get_name());
           return RatingResult {
-            rating: -1.0,
+            outcome: RatingOutcome::Reject(RejectionReason::MissingCertificates(vec![
+              required_cert.clone(),
+            ])),
             metrics: HashMap::new(),
           };
         }
-        missing_certs += 1;
+        missing_certs.push(required_cert.clone());
       }
     }
 
     let mut metrics: HashMap<String, f64> = HashMap::new();
     if ctx.config.with_diagnosis {
-      metrics.insert(String::from("ratingIncrement"), RATING_INCREMENT);
+      metrics.insert(String::from("ratingIncrement"), self.rating_increment);
       metrics.insert(String::from("hasCertificates"), has_certs as f64);
-      metrics.insert(String::from("missingCertificates"), missing_certs as f64);
+      metrics.insert(
+        String::from("missingCertificates"),
+        missing_certs.len() as f64,
+      );
       metrics.insert(String::from("weightedScore"), weighted_score);
     }
-    let rating = if missing_certs == 0 {
-      weighted_score
+    let outcome = if missing_certs.is_empty() {
+      RatingOutcome::Pass(weighted_score * (1.0 - self.rating_increment))
     } else {
-      -1.0
+      RatingOutcome::Reject(RejectionReason::MissingCertificates(missing_certs))
     };
 
-    log::debug!("Rule {} completed; score {}", self.get_name(), rating);
-    RatingResult { rating, metrics }
+    log::debug!("Rule {} completed; outcome {:?}", self.get_name(), outcome);
+    RatingResult { outcome, metrics }
   }
 }
@@ -0,0 +1,142 @@
+use super::config::EvaluationContext;
+use super::match_rating::{date_metric, MatchRating, RatingOutcome, RatingResult, RejectionReason};
+use crate::domain::weekday::Weekday;
+use chrono::Datelike;
+use std::collections::HashMap;
+
+pub struct ScheduleCoverage {
+  weight_value: f64,
+  minimum_coverage: Option<f64>,
+}
+
+impl ScheduleCoverage {
+  pub fn new(weight: f64, minimum_coverage: Option<f64>) -> ScheduleCoverage {
+    ScheduleCoverage {
+      weight_value: weight,
+      minimum_coverage,
+    }
+  }
+}
+
+impl MatchRating for ScheduleCoverage {
+  fn get_name(&self) -> &str {
+    "ScheduleCoverage"
+  }
+
+  fn get_weight(&self) -> f64 {
+    self.weight_value
+  }
+
+  fn determine_rating(&self, ctx: &EvaluationContext) -> RatingResult {
+    log::debug!(
+      "Running rule {} for Worker {} and Job {}",
+      self.get_name(),
+      ctx.worker.user_id,
+      ctx.job.job_id
+    );
+    let schedule = match &ctx.job.schedule {
+      Some(schedule) => schedule,
+      None => {
+        log::debug!("Job has no schedule; {} does not apply", self.get_name());
+        return RatingResult {
+          outcome: RatingOutcome::NotApplicable,
+          metrics: HashMap::new(),
+        };
+      }
+    };
+
+    let working_days = schedule.working_days();
+    let availability = &ctx.worker.availability;
+    let overlap_minutes: Option<Vec<i64>> = schedule.shift.map(|shift| {
+      working_days
+        .iter()
+        .map(|d| availability.overlap_minutes(Weekday::from(d.weekday()), &shift))
+        .collect()
+    });
+    let day_coverage: Vec<f64> = match (&overlap_minutes, schedule.shift) {
+      (Some(overlap_minutes), Some(shift)) => overlap_minutes
+        .iter()
+        .map(|m| *m as f64 / shift.duration_minutes() as f64)
+        .collect(),
+      _ => working_days
+        .iter()
+        .map(|d| {
+          if availability.is_available_on(Weekday::from(d.weekday())) {
+            1.0
+          } else {
+            0.0
+          }
+        })
+        .collect(),
+    };
+    let covered_days = day_coverage.iter().filter(|c| **c > 0.0).count();
+    let coverage = day_coverage.iter().sum::<f64>() / working_days.len() as f64;
+    let minimum_coverage = schedule.minimum_coverage.or(self.minimum_coverage);
+
+    let mut metrics: HashMap<String, f64> = HashMap::new();
+    if ctx.config.with_diagnosis {
+      metrics.insert(String::from("scheduledDays"), working_days.len() as f64);
+      metrics.insert(String::from("coveredDays"), covered_days as f64);
+      metrics.insert(String::from("coverage"), coverage);
+      if let (Some(overlap_minutes), Some(shift)) = (&overlap_minutes, schedule.shift) {
+        metrics.insert(
+          String::from("overlapMinutes"),
+          overlap_minutes.iter().sum::<i64>() as f64,
+        );
+        metrics.insert(
+          String::from("shiftMinutes"),
+          (shift.duration_minutes() * working_days.len() as i64) as f64,
+        );
+      }
+      if let Some(minimum_coverage) = minimum_coverage {
+        metrics.insert(String::from("minimumCoverage"), minimum_coverage);
+      }
+    }
+
+    let blackout = working_days
+      .iter()
+      .find_map(|d| ctx.worker.blackout_on(*d).map(|blackout| (*d, blackout)));
+    if let Some((date, (position, range))) = blackout {
+      log::debug!(
+        "Scheduled day {} falls in blackout {} of worker {}",
+        date,
+        position,
+        ctx.worker.user_id
+      );
+      if ctx.config.with_diagnosis {
+        metrics.insert(String::from("blackoutIndex"), position as f64);
+        metrics.insert(String::from("blackoutStartDate"), date_metric(range.start));
+        metrics.insert(String::from("blackoutEndDate"), date_metric(range.end));
+        metrics.insert(String::from("blackoutScheduledDate"), date_metric(date));
+      }
+      return RatingResult {
+        outcome: RatingOutcome::Reject(RejectionReason::BlackoutDate {
+          start: range.start,
+          end: range.end,
+        }),
+        metrics,
+      };
+    }
+
+    if let Some(minimum_coverage) = minimum_coverage.filter(|m| coverage < *m) {
+      log::debug!(
+        "Worker covers {} of the schedule, below the minimum of {}",
+        coverage,
+        minimum_coverage
+      );
+      return RatingResult {
+        outcome: RatingOutcome::Reject(RejectionReason::InsufficientCoverage {
+          coverage,
+          minimum_coverage,
+        }),
+        metrics,
+      };
+    }
+
+    log::debug!("Rule {} completed with score {}", self.get_name(), coverage);
+    RatingResult {
+      outcome: RatingOutcome::Pass(coverage),
+      metrics,
+    }
+  }
+}
@@ -0,0 +1,98 @@
+use super::config::EvaluationContext;
+use super::match_rating::{MatchRating, RatingOutcome, RatingResult, RejectionReason};
+use std::collections::HashMap;
+
+pub struct SkillsMatch {
+  weight_value: f64,
+}
+
+impl SkillsMatch {
+  pub fn new(weight: f64) -> SkillsMatch {
+    SkillsMatch {
+      weight_value: weight,
+    }
+  }
+}
+
+impl MatchRating for SkillsMatch {
+  fn get_name(&self) -> &str {
+    "SkillsMatch"
+  }
+
+  fn get_weight(&self) -> f64 {
+    self.weight_value
+  }
+
+  fn determine_rating(&self, ctx: &EvaluationContext) -> RatingResult {
+    log::debug!(
+      "Running rule {} for Worker {} and Job {}",
+      self.get_name(),
+      ctx.worker.user_id,
+      ctx.job.job_id
+    );
+    let required = &ctx.job.required_skills;
+    let preferred = &ctx.job.preferred_skills;
+    let uses_about_text = required.is_empty() && preferred.is_empty();
+
+    let (matched, missing, missing_required, fit) = if uses_about_text {
+      log::debug!("Job has no skills listed; matching against job description");
+      let skills = ctx.worker.skills.len();
+      let mentioned = ctx.worker.skills.count_mentioned_in(&ctx.job.about_terms);
+      let fit = if skills > 0 {
+        mentioned as f64 / skills as f64
+      } else {
+        0.0
+      };
+      (mentioned, skills - mentioned, Vec::new(), fit)
+    } else {
+      let missing_required = required.missing_from(&ctx.worker.skills);
+      if !missing_required.is_empty() && ctx.config.short_circuit_failures {
+        log::debug!("{} Short-circuiting rule failure", self.get_name());
+        return RatingResult {
+          outcome: RatingOutcome::Reject(RejectionReason::MissingRequiredSkills(missing_required)),
+          metrics: HashMap::new(),
+        };
+      }
+      let matched_required = required.len() - missing_required.len();
+      let matched_preferred = preferred.count_found_in(&ctx.worker.skills);
+      let matched = matched_required + matched_preferred;
+      let total = required.len() + preferred.len();
+      (
+        matched,
+        total - matched,
+        missing_required,
+        matched as f64 / total as f64,
+      )
+    };
+
+    let mut metrics: HashMap<String, f64> = HashMap::new();
+    if ctx.config.with_diagnosis {
+      metrics.insert(String::from("requiredSkills"), required.len() as f64);
+      metrics.insert(String::from("preferredSkills"), preferred.len() as f64);
+      metrics.insert(String::from("matchedSkills"), matched as f64);
+      metrics.insert(String::from("missingSkills"), missing as f64);
+      metrics.insert(
+        String::from("missingRequiredSkills"),
+        missing_required.len() as f64,
+      );
+      metrics.insert(
+        String::from("usedAboutText"),
+        if uses_about_text { 1.0 } else { 0.0 },
+      );
+      metrics.insert(String::from("skillFit"), fit);
+    }
+
+    let outcome = if !missing_required.is_empty() {
+      log::debug!("Worker is missing required skills");
+      RatingOutcome::Reject(RejectionReason::MissingRequiredSkills(missing_required))
+    } else {
+      RatingOutcome::Pass(fit)
+    };
+    log::debug!(
+      "Rule {} completed with outcome {:?}",
+      self.get_name(),
+      outcome
+    );
+    RatingResult { outcome, metrics }
+  }
+}
@@ -0,0 +1,117 @@
+use crate::domain::config::TimezoneConfig;
+use crate::domain::timezone::TimezoneResolver;
+use crate::engine::available_on_start_day::AvailableOnStartDay;
+use crate::engine::config::{EvaluationConfig, EvaluationContext};
+use crate::engine::match_rating::{MatchRating, RatingOutcome, RatingResult, RejectionReason};
+use crate::fixtures::{job, worker};
+use chrono::NaiveDate;
+use serde_json::json;
+use std::sync::Arc;
+
+const DIAGNOSIS: EvaluationConfig = EvaluationConfig {
+  with_diagnosis: true,
+  short_circuit_failures: false,
+};
+
+fn rate(
+  minimum_shift_overlap: Option<f64>,
+  worker_overrides: serde_json::Value,
+  job_overrides: serde_json::Value,
+) -> RatingResult {
+  let config: TimezoneConfig = serde_json::from_value(json!({})).unwrap();
+  let rule = AvailableOnStartDay::new(
+    1.0,
+    Arc::new(TimezoneResolver::new(&config).ok().unwrap()),
+    minimum_shift_overlap,
+  );
+  let (worker, job) = (worker(1, worker_overrides), job(7, job_overrides));
+  rule.determine_rating(&EvaluationContext::new(&worker, &job, &DIAGNOSIS))
+}
+
+#[test]
+fn test_checks_the_start_day_in_the_jobs_timezone() {
+  // Monday 02:00 UTC is still Sunday evening in New York.
+  let job = json!({ "startDate": "2021-06-07T02:00:00.000Z", "timezone": "America/New_York" });
+  let result = rate(None, json!({}), job.clone());
+  assert_eq!(result.outcome, RatingOutcome::Pass(1.0));
+  assert_eq!(result.metrics["localStartDate"], 20210606.0);
+  assert_eq!(result.metrics["startDayIndex"], 7.0);
+  assert_eq!(result.metrics["utcOffsetMinutes"], -240.0);
+
+  let result = rate(
+    None,
+    json!({ "availability": [{ "title": "Monday", "dayIndex": 1 }] }),
+    job,
+  );
+  assert_eq!(result.outcome, RatingOutcome::Pass(0.0));
+  assert_eq!(result.metrics["hasStartDay"], 0.0);
+}
+
+fn shift_job() -> serde_json::Value {
+  json!({ "schedule": {
+    "startDate": "2015-11-01", "endDate": "2015-11-01", "shiftStart": "09:00", "shiftEnd": "17:00",
+  } })
+}
+
+fn morning_worker() -> serde_json::Value {
+  json!({ "availability": [
+    { "title": "Sunday", "dayIndex": 7, "startTime": "09:00", "endTime": "13:00" },
+  ] })
+}
+
+#[test]
+fn test_scores_the_share_of_the_shift_covered() {
+  let result = rate(None, morning_worker(), shift_job());
+  assert_eq!(result.outcome, RatingOutcome::Pass(0.5));
+  assert_eq!(result.metrics["overlapMinutes"], 240.0);
+  assert_eq!(result.metrics["shiftMinutes"], 480.0);
+  assert_eq!(
+    rate(Some(0.5), morning_worker(), shift_job()).outcome,
+    RatingOutcome::Pass(0.5)
+  );
+}
+
+#[test]
+fn test_rejects_shift_overlap_below_the_minimum() {
+  let result = rate(Some(0.75), morning_worker(), shift_job());
+  assert_eq!(
+    result.outcome,
+    RatingOutcome::Reject(RejectionReason::InsufficientShiftOverlap {
+      overlap_minutes: 240,
+      shift_minutes: 480,
+    })
+  );
+}
+
+#[test]
+fn test_rejects_a_blackout_covering_the_start_day() {
+  let result = rate(
+    None,
+    json!({ "blackoutDates": [
+      { "startDate": "2015-12-01", "endDate": "2015-12-02" },
+      { "startDate": "2015-10-25", "endDate": "2015-11-01" },
+    ] }),
+    json!({}),
+  );
+  assert_eq!(
+    result.outcome,
+    RatingOutcome::Reject(RejectionReason::BlackoutDate {
+      start: NaiveDate::from_ymd(2015, 10, 25),
+      end: NaiveDate::from_ymd(2015, 11, 1),
+    })
+  );
+  assert_eq!(result.metrics["blackoutIndex"], 1.0);
+  assert_eq!(result.metrics["blackoutStartDate"], 20151025.0);
+  assert_eq!(result.metrics["blackoutEndDate"], 20151101.0);
+}
+
+#[test]
+fn test_ignores_a_blackout_ending_the_day_before_the_start() {
+  let result = rate(
+    None,
+    json!({ "blackoutDates": [{ "startDate": "2015-10-25", "endDate": "2015-10-31" }] }),
+    json!({}),
+  );
+  assert_eq!(result.outcome, RatingOutcome::Pass(1.0));
+  assert!(!result.metrics.contains_key("blackoutIndex"));
+}
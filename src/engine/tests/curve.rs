@@ -0,0 +1,77 @@
+use crate::engine::curve::ScoringCurve;
+use serde_json::json;
+
+fn curve(value: serde_json::Value) -> ScoringCurve {
+  serde_json::from_value(value).unwrap()
+}
+
+fn assert_close(actual: f64, expected: f64) {
+  assert!(
+    (actual - expected).abs() < 1e-9,
+    "expected {} but got {}",
+    expected,
+    actual
+  );
+}
+
+#[test]
+fn test_applies_linear_curve_with_clamping() {
+  let linear = curve(json!({ "type": "linear", "zero": 30, "one": 0 }));
+  assert_close(linear.apply(0.0), 1.0);
+  assert_close(linear.apply(15.0), 0.5);
+  assert_close(linear.apply(45.0), 0.0);
+}
+
+#[test]
+fn test_applies_exponential_decay() {
+  let decay = curve(json!({ "type": "exponential_decay", "half_life": 10 }));
+  assert_close(decay.apply(0.0), 1.0);
+  assert_close(decay.apply(10.0), 0.5);
+  assert_close(decay.apply(20.0), 0.25);
+}
+
+#[test]
+fn test_applies_logistic_curve() {
+  let logistic = curve(json!({ "type": "logistic", "midpoint": 20, "steepness": 0.5 }));
+  assert_close(logistic.apply(20.0), 0.5);
+  assert!(logistic.apply(80.0) < 1.0);
+  assert!(logistic.apply(80.0) > 0.99);
+}
+
+#[test]
+fn test_applies_step_curve() {
+  let step = curve(json!({ "type": "step", "threshold": 20 }));
+  assert_close(step.apply(19.99), 0.0);
+  assert_close(step.apply(20.0), 1.0);
+}
+
+#[test]
+fn test_interpolates_piecewise_curve() {
+  let piecewise = curve(json!({ "type": "piecewise", "points": [[0, 0], [10, 0.5], [30, 1]] }));
+  assert_close(piecewise.apply(-5.0), 0.0);
+  assert_close(piecewise.apply(5.0), 0.25);
+  assert_close(piecewise.apply(20.0), 0.75);
+  assert_close(piecewise.apply(100.0), 1.0);
+}
+
+#[test]
+fn test_validates_curves() {
+  assert!(
+    curve(json!({ "type": "exponential_decay", "half_life": 0 }))
+      .validate()
+      .is_err()
+  );
+  assert!(
+    curve(json!({ "type": "piecewise", "points": [[10, 1], [0, 0]] }))
+      .validate()
+      .is_err()
+  );
+  assert!(curve(json!({ "type": "linear", "zero": 1, "one": 1 }))
+    .validate()
+    .is_err());
+  assert!(
+    curve(json!({ "type": "logistic", "midpoint": 1, "steepness": 2 }))
+      .validate()
+      .is_ok()
+  );
+}
@@ -0,0 +1,122 @@
+use crate::domain::geo::GeoPoint;
+use crate::engine::distance::{
+  GeographicDistanceEvaluator, HaversineDistanceEvaluator, PythagorasDistanceEvaluator,
+  VincentyDistanceEvaluator,
+};
+
+struct CityPair {
+  from: (f64, f64),
+  to: (f64, f64),
+  distance_km: f64,
+}
+
+const LONDON: (f64, f64) = (51.5074, -0.1278);
+const PARIS: (f64, f64) = (48.8566, 2.3522);
+const NEW_YORK: (f64, f64) = (40.7128, -74.0060);
+const LOS_ANGELES: (f64, f64) = (34.0522, -118.2437);
+const SYDNEY: (f64, f64) = (-33.8688, 151.2093);
+const MELBOURNE: (f64, f64) = (-37.8136, 144.9631);
+const NASHVILLE: (f64, f64) = (36.12, -86.67);
+const LAX: (f64, f64) = (33.94, -118.40);
+
+const CITY_PAIRS: [CityPair; 4] = [
+  CityPair {
+    from: LONDON,
+    to: PARIS,
+    distance_km: 343.5,
+  },
+  CityPair {
+    from: NEW_YORK,
+    to: LOS_ANGELES,
+    distance_km: 3936.0,
+  },
+  CityPair {
+    from: SYDNEY,
+    to: MELBOURNE,
+    distance_km: 713.4,
+  },
+  CityPair {
+    from: NASHVILLE,
+    to: LAX,
+    distance_km: 2886.4,
+  },
+];
+
+fn point(coordinates: (f64, f64)) -> GeoPoint {
+  GeoPoint::new(coordinates.0, coordinates.1).unwrap()
+}
+
+fn assert_within(actual: f64, expected: f64, tolerance: f64) {
+  assert!(
+    (actual - expected).abs() <= expected * tolerance,
+    "expected {} to be within {}% of {}",
+    actual,
+    tolerance * 100.0,
+    expected
+  );
+}
+
+fn assert_city_pairs(evaluator: &dyn GeographicDistanceEvaluator, tolerance: f64) {
+  for pair in CITY_PAIRS.iter() {
+    let distance = evaluator.determine_distance(&point(pair.from), &point(pair.to));
+    assert_within(distance, pair.distance_km, tolerance);
+    let reverse = evaluator.determine_distance(&point(pair.to), &point(pair.from));
+    assert!((distance - reverse).abs() < 1e-6);
+  }
+}
+
+#[test]
+fn test_haversine_matches_city_pairs() {
+  assert_city_pairs(&HaversineDistanceEvaluator::new(), 0.002);
+}
+
+#[test]
+fn test_vincenty_matches_city_pairs() {
+  assert_city_pairs(&VincentyDistanceEvaluator::new(), 0.005);
+}
+
+#[test]
+fn test_vincenty_matches_reference_geodesic() {
+  let flinders_peak = point((-37.951_033_416_666_7, 144.424_867_888_888_9));
+  let buninyong = point((-37.652_821_138_888_9, 143.926_495_527_777_8));
+
+  let distance = VincentyDistanceEvaluator::new().determine_distance(&flinders_peak, &buninyong);
+  assert!((distance - 54.972_271).abs() < 1e-6);
+}
+
+#[test]
+fn test_vincenty_handles_nearly_antipodal_points() {
+  let distance =
+    VincentyDistanceEvaluator::new().determine_distance(&point((0.0, 0.0)), &point((0.5, 179.7)));
+  assert_within(distance, 19_936.0, 0.01);
+}
+
+#[test]
+fn test_pythagoras_approximates_short_distances() {
+  let evaluator = PythagorasDistanceEvaluator::new();
+  assert_within(
+    evaluator.determine_distance(&point(LONDON), &point(PARIS)),
+    343.5,
+    0.01,
+  );
+  assert_within(
+    evaluator.determine_distance(&point((49.70, 13.90)), &point((49.78, 13.90))),
+    8.9,
+    0.01,
+  );
+}
+
+#[test]
+fn test_identical_points_are_zero_distance() {
+  let evaluators: Vec<Box<dyn GeographicDistanceEvaluator>> = vec![
+    Box::new(PythagorasDistanceEvaluator::new()),
+    Box::new(HaversineDistanceEvaluator::new()),
+    Box::new(VincentyDistanceEvaluator::new()),
+  ];
+  for evaluator in evaluators {
+    assert_eq!(
+      evaluator.determine_distance(&point(SYDNEY), &point(SYDNEY)),
+      0.0
+    );
+  }
+}
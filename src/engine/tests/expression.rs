@@ -0,0 +1,108 @@
+use crate::domain::job::Job;
+use crate::domain::worker::Worker;
+use crate::engine::expression::{Expression, ValueType};
+use crate::fixtures;
+use serde_json::json;
+
+fn worker() -> Worker {
+  fixtures::worker(
+    1,
+    json!({ "certificates": ["Forklift"], "skills": ["Cashier", "Customer Service"] }),
+  )
+}
+
+fn job() -> Job {
+  fixtures::job(7, json!({}))
+}
+
+#[test]
+fn test_evaluates_filters() {
+  let (worker, job) = (worker(), job());
+  let filter = Expression::compile("job.company != \"Acme\" or worker.rating >= 4").unwrap();
+  assert_eq!(filter.value_type(), ValueType::Bool);
+  assert!(filter.evaluate_bool(&worker, &job));
+
+  let filter =
+    Expression::compile("'customer service' in worker.skills and not worker.active").unwrap();
+  assert!(!filter.evaluate_bool(&worker, &job));
+}
+
+#[test]
+fn test_evaluates_scores() {
+  let (worker, job) = (worker(), job());
+  let score = Expression::compile("if job.billRate > 20 then 1 else 0").unwrap();
+  assert_eq!(score.value_type(), ValueType::Number);
+  assert_eq!(score.evaluate_number(&worker, &job), 1.0);
+
+  let score = Expression::compile("(job.billRate - 20) * 2 + -job.workersRequired").unwrap();
+  assert_eq!(score.evaluate_number(&worker, &job), 3.0);
+}
+
+#[test]
+fn test_compares_text_like_in() {
+  let (worker, job) = (worker(), job());
+  let filter = Expression::compile("worker.transportation == \"car\"").unwrap();
+  assert!(filter.evaluate_bool(&worker, &job));
+
+  let filter = Expression::compile("job.company != 'ACME'").unwrap();
+  assert!(!filter.evaluate_bool(&worker, &job));
+}
+
+#[test]
+fn test_rejects_deep_nesting() {
+  let source = format!("{}1{}", "(".repeat(10), ")".repeat(10));
+  assert!(Expression::compile(&source).is_ok());
+
+  for source in [
+    format!("{}1{}", "(".repeat(100), ")".repeat(100)),
+    format!("{}1", "-".repeat(100)),
+    format!("{}true", "not ".repeat(100)),
+    format!("{}1{}", "if true then ".repeat(100), " else 0".repeat(100)),
+  ] {
+    let error = Expression::compile(&source).err().unwrap();
+    assert!(error
+      .to_string()
+      .ends_with("expression is nested more than 64 levels deep"));
+  }
+}
+
+#[test]
+fn test_reports_parse_errors() {
+  let error = Expression::compile("worker.rating >= ").err().unwrap();
+  assert_eq!(
+    error.to_string(),
+    "at column 18: unexpected end of expression"
+  );
+
+  let error = Expression::compile("worker.ratng >= 4").err().unwrap();
+  assert_eq!(
+    error.to_string(),
+    "at column 1: unknown field 'worker.ratng'"
+  );
+
+  let error = Expression::compile("job.company == 'Acme").err().unwrap();
+  assert_eq!(error.to_string(), "at column 16: unterminated string");
+}
+
+#[test]
+fn test_reports_type_errors() {
+  let error = Expression::compile("job.company >= 4").err().unwrap();
+  assert_eq!(
+    error.to_string(),
+    "at column 1: '>=' expects number but found text"
+  );
+
+  let error = Expression::compile("if worker.active then 1 else 'no'")
+    .err()
+    .unwrap();
+  assert_eq!(
+    error.to_string(),
+    "at column 30: 'else' branch is text but 'then' branch is number"
+  );
+
+  let error = Expression::compile("worker.rating and true").err().unwrap();
+  assert_eq!(
+    error.to_string(),
+    "at column 1: 'and' expects boolean but found number"
+  );
+}
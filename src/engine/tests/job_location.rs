@@ -0,0 +1,68 @@
+use crate::domain::config::DistanceEvaluatorType;
+use crate::domain::worker::Worker;
+use crate::engine::config::{EvaluationConfig, EvaluationContext};
+use crate::engine::job_location::JobLocation;
+use crate::engine::match_rating::{MatchRating, RatingOutcome, RejectionReason};
+use crate::engine::registry::build_distance_evaluator;
+use crate::fixtures::{job, worker, worker_dto};
+use serde_json::json;
+
+const DIAGNOSIS: EvaluationConfig = EvaluationConfig {
+  with_diagnosis: true,
+  short_circuit_failures: false,
+};
+
+fn rule() -> JobLocation {
+  JobLocation::new(
+    1.0,
+    build_distance_evaluator(&DistanceEvaluatorType::Pythagoras),
+    None,
+  )
+}
+
+#[test]
+fn test_rejects_a_worker_without_a_location() {
+  let mut worker = worker(1, json!({}));
+  worker.job_search_address.location = None;
+  let job = job(7, json!({}));
+  let result = rule().determine_rating(&EvaluationContext::new(&worker, &job, &DIAGNOSIS));
+  assert_eq!(
+    result.outcome,
+    RatingOutcome::Reject(RejectionReason::UnknownLocation {
+      worker: true,
+      job: false,
+    })
+  );
+}
+
+#[test]
+fn test_rejects_a_job_without_a_location() {
+  let worker = worker(1, json!({}));
+  let mut job = job(7, json!({}));
+  job.location = None;
+  let result = rule().determine_rating(&EvaluationContext::new(&worker, &job, &DIAGNOSIS));
+  assert_eq!(
+    result.outcome,
+    RatingOutcome::Reject(RejectionReason::UnknownLocation {
+      worker: false,
+      job: true,
+    })
+  );
+}
+
+#[test]
+fn test_rejects_an_unknown_distance_unit() {
+  let (worker, _) = Worker::parse(worker_dto(
+    1,
+    json!({ "jobSearchAddress": { "latitude": "49.78", "longitude": "13.9", "maxJobDistance": 30, "unit": "furlongs" } }),
+  ))
+  .unwrap();
+  let job = job(7, json!({}));
+  let result = rule().determine_rating(&EvaluationContext::new(&worker, &job, &DIAGNOSIS));
+  assert_eq!(
+    result.outcome,
+    RatingOutcome::Reject(RejectionReason::UnknownDistanceUnit(String::from(
+      "furlongs"
+    )))
+  );
+}
@@ -0,0 +1,58 @@
+use crate::engine::match_rating::{date_metric, RatingOutcome, RejectionReason};
+use chrono::NaiveDate;
+
+#[test]
+fn test_only_passing_outcomes_score() {
+  assert_eq!(RatingOutcome::Pass(2.5).score(), 2.5);
+  assert_eq!(RatingOutcome::NotApplicable.score(), 0.0);
+  assert_eq!(
+    RatingOutcome::Reject(RejectionReason::DriversLicenseRequired).score(),
+    0.0
+  );
+}
+
+#[test]
+fn test_describes_rejection_reasons() {
+  let reason =
+    RejectionReason::MissingCertificates(vec![String::from("Forklift"), String::from("First Aid")]);
+  assert_eq!(reason.code(), "MISSING_CERTIFICATES");
+  assert_eq!(
+    reason.to_string(),
+    "Missing certificates: Forklift, First Aid"
+  );
+
+  let reason = RejectionReason::OutsideSearchArea {
+    distance: 42.04,
+    max_job_distance: 30.0,
+  };
+  assert_eq!(reason.code(), "OUTSIDE_SEARCH_AREA");
+  assert_eq!(reason.to_string(), "Job is 42.0km away; maximum is 30.0km");
+
+  let reason = RejectionReason::UnknownLocation {
+    worker: true,
+    job: false,
+  };
+  assert_eq!(reason.code(), "UNKNOWN_LOCATION");
+  assert_eq!(reason.to_string(), "Worker location is unknown");
+
+  let reason = RejectionReason::UnknownDistanceUnit(String::from("furlongs"));
+  assert_eq!(reason.code(), "UNKNOWN_DISTANCE_UNIT");
+  assert_eq!(
+    reason.to_string(),
+    "Worker search distance unit 'furlongs' is unknown"
+  );
+}
+
+#[test]
+fn test_describes_blackout_dates() {
+  let reason = RejectionReason::BlackoutDate {
+    start: NaiveDate::from_ymd(2021, 6, 1),
+    end: NaiveDate::from_ymd(2021, 6, 14),
+  };
+  assert_eq!(reason.code(), "BLACKOUT_DATE");
+  assert_eq!(
+    reason.to_string(),
+    "Worker is unavailable from 2021-06-01 to 2021-06-14"
+  );
+  assert_eq!(date_metric(NaiveDate::from_ymd(2021, 6, 1)), 20210601.0);
+}
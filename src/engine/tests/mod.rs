@@ -0,0 +1,11 @@
+pub mod available_on_start_day;
+pub mod curve;
+pub mod distance;
+pub mod expression;
+pub mod job_location;
+pub mod match_rating;
+pub mod registry;
+pub mod schedule_coverage;
+pub mod skills;
+pub mod travel_time;
+pub mod worker_rating;
@@ -0,0 +1,169 @@
+use crate::domain::config::{Config, DistanceEvaluatorType};
+use crate::engine::registry::build_match_ratings;
+use serde_json::json;
+
+fn config_with_rules(rules: serde_json::Value) -> Config {
+  serde_json::from_value(json!({
+    "app_name": "test",
+    "jobs_to_return": 3,
+    "workers_to_return": 5,
+    "repository": { "type": "file", "directory": "data" },
+    "rules": rules,
+  }))
+  .unwrap()
+}
+
+#[test]
+fn test_builds_rules_in_configured_order() {
+  let config = config_with_rules(json!([
+    { "name": "PayRate", "weight": 0.4 },
+    { "name": "CanDrive", "weight": 0 },
+    { "name": "HasRequiredCertificates", "weight": 2, "params": { "rating_increment": 0.5 } },
+  ]));
+  let ratings = build_match_ratings(&config).ok().unwrap();
+  let names: Vec<&str> = ratings.iter().map(|r| r.get_name()).collect();
+  assert_eq!(
+    names,
+    vec!["PayRate", "CanDrive", "HasRequiredCertificates"]
+  );
+  assert_eq!(ratings[2].get_weight(), 2.0);
+}
+
+#[test]
+fn test_defaults_to_pythagoras_distance() {
+  let config = config_with_rules(json!([{ "name": "JobLocation", "weight": 1 }]));
+  assert!(matches!(
+    config.distance_evaluator,
+    DistanceEvaluatorType::Pythagoras
+  ));
+}
+
+#[test]
+fn test_reports_all_configuration_errors() {
+  let config = config_with_rules(json!([
+    { "name": "Teleport", "weight": 1 },
+    { "name": "PayRate", "weight": -1 },
+    { "name": "HasRequiredCertificates", "weight": 2, "params": { "rating_increment": "high", "bonus": 1 } },
+    { "name": "PayRate", "weight": 1 },
+  ]));
+  let errors = build_match_ratings(&config).err().unwrap();
+  assert_eq!(errors.len(), 5);
+  assert!(errors[0].starts_with("rules[0] (Teleport): unknown rule"));
+  assert_eq!(
+    errors[1],
+    "rules[1] (PayRate): weight must be a non-negative number, got -1"
+  );
+  assert_eq!(
+    errors[2],
+    "rules[2] (HasRequiredCertificates): unknown parameter 'bonus'"
+  );
+  assert_eq!(
+    errors[3],
+    "rules[2] (HasRequiredCertificates): parameter 'rating_increment' must be a positive number, got \"high\""
+  );
+  assert_eq!(
+    errors[4],
+    "rules[3] (PayRate): rule is configured more than once"
+  );
+}
+
+#[test]
+fn test_requires_at_least_one_rule() {
+  let config = config_with_rules(json!([]));
+  assert!(build_match_ratings(&config).is_err());
+}
+
+#[test]
+fn test_builds_expression_rules() {
+  let config = config_with_rules(json!([
+    { "name": "AcmeMinimumRating", "weight": 0, "filter": "job.company != 'Acme' or worker.rating >= 4" },
+    { "name": "HighPayBoost", "weight": 2, "score": "if job.billRate > 20 then 1 else 0" },
+  ]));
+  let ratings = build_match_ratings(&config).ok().unwrap();
+  assert_eq!(ratings[0].get_name(), "AcmeMinimumRating");
+  assert_eq!(ratings[1].get_weight(), 2.0);
+}
+
+#[test]
+fn test_reports_expression_errors() {
+  let config = config_with_rules(json!([
+    { "name": "Broken", "weight": 1, "filter": "worker.rating >" },
+    { "name": "Untyped", "weight": 1, "score": "worker.active" },
+    { "name": "PayRate", "weight": 1, "filter": "true" },
+  ]));
+  let errors = build_match_ratings(&config).err().unwrap();
+  assert_eq!(
+    errors,
+    vec![
+      "rules[0] (Broken): filter expression error at column 16: unexpected end of expression",
+      "rules[1] (Untyped): score expression must be number but is boolean",
+      "rules[2] (PayRate): expression rules cannot use the name of a built-in rule",
+    ]
+  );
+}
+
+#[test]
+fn test_reports_invalid_curves() {
+  let config = config_with_rules(json!([
+    { "name": "PayRate", "weight": 1, "params": { "curve": { "type": "cubic" } } },
+    { "name": "JobLocation", "weight": 1, "params": { "curve": { "type": "exponential_decay", "half_life": -1 } } },
+    { "name": "CanDrive", "weight": 0, "params": { "curve": { "type": "step", "threshold": 1 } } },
+  ]));
+  let errors = build_match_ratings(&config).err().unwrap();
+  assert_eq!(errors.len(), 3);
+  assert!(errors[0].starts_with("rules[0] (PayRate): invalid curve: unknown variant `cubic`"));
+  assert_eq!(
+    errors[1],
+    "rules[1] (JobLocation): invalid curve: exponential_decay curve needs a positive 'half_life'"
+  );
+  assert_eq!(errors[2], "rules[2] (CanDrive): unknown parameter 'curve'");
+}
+
+#[test]
+fn test_requires_rating_increment_below_one() {
+  let config = config_with_rules(json!([
+    { "name": "HasRequiredCertificates", "weight": 5, "params": { "rating_increment": 1.5 } },
+  ]));
+  assert_eq!(
+    build_match_ratings(&config).err().unwrap(),
+    vec![
+      "rules[0] (HasRequiredCertificates): parameter 'rating_increment' must be below 1, got 1.5"
+    ]
+  );
+}
+
+#[test]
+fn test_requires_shift_overlap_fraction() {
+  let config = config_with_rules(json!([
+    { "name": "AvailableOnStartDay", "weight": 10, "params": { "min_shift_overlap": 2 } },
+  ]));
+  assert_eq!(
+    build_match_ratings(&config).err().unwrap(),
+    vec!["rules[0] (AvailableOnStartDay): parameter 'min_shift_overlap' must be between 0 and 1"]
+  );
+}
+
+#[test]
+fn test_requires_positive_travel_speeds() {
+  let mut config = config_with_rules(json!([{ "name": "TravelTime", "weight": 4 }]));
+  config.travel.speeds_kmh.insert(String::from("CAR"), 0.0);
+  config.travel.speeds_kmh.insert(String::from("BIKE"), -15.0);
+  config.travel.default_speed_kmh = 0.0;
+  assert_eq!(
+    build_match_ratings(&config).err().unwrap(),
+    vec![
+      "rules[0] (TravelTime): travel.speeds_kmh.BIKE must be a positive number, got -15",
+      "rules[0] (TravelTime): travel.speeds_kmh.CAR must be a positive number, got 0",
+      "rules[0] (TravelTime): travel.default_speed_kmh must be a positive number, got 0",
+    ]
+  );
+}
+
+#[test]
+fn test_requires_zero_weight_for_gate_only_rules() {
+  let config = config_with_rules(json!([{ "name": "CanDrive", "weight": 1 }]));
+  assert_eq!(
+    build_match_ratings(&config).err().unwrap(),
+    vec!["rules[0] (CanDrive): gate-only rule never adds to the rating; weight must be 0, got 1"]
+  );
+}
@@ -0,0 +1,152 @@
+use crate::engine::config::{EvaluationConfig, EvaluationContext};
+use crate::engine::match_rating::{MatchRating, RatingOutcome, RatingResult, RejectionReason};
+use crate::engine::schedule_coverage::ScheduleCoverage;
+use crate::fixtures::{job, worker};
+use chrono::NaiveDate;
+use serde_json::json;
+
+const DIAGNOSIS: EvaluationConfig = EvaluationConfig {
+  with_diagnosis: true,
+  short_circuit_failures: false,
+};
+
+fn rate(
+  minimum_coverage: Option<f64>,
+  schedule: serde_json::Value,
+  availability: serde_json::Value,
+) -> RatingResult {
+  let worker = worker(1, json!({ "availability": availability }));
+  let job = job(7, json!({ "schedule": schedule }));
+  ScheduleCoverage::new(1.0, minimum_coverage)
+    .determine_rating(&EvaluationContext::new(&worker, &job, &DIAGNOSIS))
+}
+
+// Monday 2015-11-02 through Friday 2015-11-06; the worker covers three of the five days.
+fn weekday_rating(minimum_coverage: Option<f64>, schedule_minimum: Option<f64>) -> RatingResult {
+  let mut schedule = json!({ "startDate": "2015-11-02", "endDate": "2015-11-06" });
+  if let Some(minimum) = schedule_minimum {
+    schedule["minimumCoverage"] = json!(minimum);
+  }
+  rate(
+    minimum_coverage,
+    schedule,
+    json!([
+      { "title": "Monday", "dayIndex": 1 },
+      { "title": "Tuesday", "dayIndex": 2 },
+      { "title": "Wednesday", "dayIndex": 3 },
+    ]),
+  )
+}
+
+#[test]
+fn test_scores_the_share_of_covered_days() {
+  let result = weekday_rating(None, None);
+  assert_eq!(result.outcome, RatingOutcome::Pass(0.6));
+  assert_eq!(result.metrics["scheduledDays"], 5.0);
+  assert_eq!(result.metrics["coveredDays"], 3.0);
+}
+
+#[test]
+fn test_applies_the_coverage_threshold() {
+  assert_eq!(
+    weekday_rating(Some(0.6), None).outcome,
+    RatingOutcome::Pass(0.6)
+  );
+
+  let result = weekday_rating(Some(0.8), None);
+  assert_eq!(result.metrics["minimumCoverage"], 0.8);
+  assert_eq!(
+    result.outcome,
+    RatingOutcome::Reject(RejectionReason::InsufficientCoverage {
+      coverage: 0.6,
+      minimum_coverage: 0.8,
+    })
+  );
+
+  // The job's own minimum overrides the rule's.
+  assert_eq!(
+    weekday_rating(Some(0.8), Some(0.5)).outcome,
+    RatingOutcome::Pass(0.6)
+  );
+  assert!(matches!(
+    weekday_rating(Some(0.5), Some(0.8)).outcome,
+    RatingOutcome::Reject(RejectionReason::InsufficientCoverage { .. })
+  ));
+}
+
+#[test]
+fn test_describes_insufficient_coverage() {
+  let reason = RejectionReason::InsufficientCoverage {
+    coverage: 0.6,
+    minimum_coverage: 0.8,
+  };
+  assert_eq!(reason.code(), "INSUFFICIENT_COVERAGE");
+  assert_eq!(
+    reason.to_string(),
+    "Worker is available for 60% of scheduled days; minimum is 80%"
+  );
+}
+
+#[test]
+fn test_counts_overnight_shift_overlap_across_midnight() {
+  // A Monday night shift, covered 22:00-02:00 on Monday and 04:00-06:00 on Tuesday morning.
+  let result = rate(
+    None,
+    json!({
+      "startDate": "2015-11-02", "endDate": "2015-11-02",
+      "shiftStart": "22:00", "shiftEnd": "06:00",
+    }),
+    json!([
+      { "title": "Monday", "dayIndex": 1, "startTime": "22:00", "endTime": "02:00" },
+      { "title": "Tuesday", "dayIndex": 2, "startTime": "04:00", "endTime": "08:00" },
+    ]),
+  );
+  assert_eq!(result.metrics["shiftMinutes"], 480.0);
+  assert_eq!(result.metrics["overlapMinutes"], 360.0);
+  assert_eq!(result.outcome, RatingOutcome::Pass(0.75));
+}
+
+fn rate_with_blackouts(blackout_dates: serde_json::Value) -> RatingResult {
+  let worker = worker(
+    1,
+    json!({
+      "availability": [
+        { "title": "Monday", "dayIndex": 1 },
+        { "title": "Tuesday", "dayIndex": 2 },
+        { "title": "Wednesday", "dayIndex": 3 },
+        { "title": "Thursday", "dayIndex": 4 },
+        { "title": "Friday", "dayIndex": 5 },
+      ],
+      "blackoutDates": blackout_dates,
+    }),
+  );
+  let job = job(
+    7,
+    json!({ "schedule": { "startDate": "2015-11-02", "endDate": "2015-11-06" } }),
+  );
+  ScheduleCoverage::new(1.0, None)
+    .determine_rating(&EvaluationContext::new(&worker, &job, &DIAGNOSIS))
+}
+
+#[test]
+fn test_rejects_a_blackout_covering_a_later_shift() {
+  let result = rate_with_blackouts(json!([{ "startDate": "2015-11-04", "endDate": "2015-11-10" }]));
+  assert_eq!(
+    result.outcome,
+    RatingOutcome::Reject(RejectionReason::BlackoutDate {
+      start: NaiveDate::from_ymd(2015, 11, 4),
+      end: NaiveDate::from_ymd(2015, 11, 10),
+    })
+  );
+  assert_eq!(result.metrics["blackoutIndex"], 0.0);
+  assert_eq!(result.metrics["blackoutStartDate"], 20151104.0);
+  assert_eq!(result.metrics["blackoutEndDate"], 20151110.0);
+  assert_eq!(result.metrics["blackoutScheduledDate"], 20151104.0);
+}
+
+#[test]
+fn test_ignores_a_blackout_ending_the_day_before_the_schedule() {
+  let result = rate_with_blackouts(json!([{ "startDate": "2015-10-26", "endDate": "2015-11-01" }]));
+  assert_eq!(result.outcome, RatingOutcome::Pass(1.0));
+  assert!(!result.metrics.contains_key("blackoutIndex"));
+}
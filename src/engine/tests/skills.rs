@@ -0,0 +1,65 @@
+use crate::engine::config::{EvaluationConfig, EvaluationContext};
+use crate::engine::match_rating::{MatchRating, RatingOutcome, RejectionReason};
+use crate::engine::skills::SkillsMatch;
+use crate::fixtures::{job, worker};
+use serde_json::json;
+
+const DIAGNOSIS: EvaluationConfig = EvaluationConfig {
+  with_diagnosis: true,
+  short_circuit_failures: false,
+};
+
+fn rate(
+  worker_skills: serde_json::Value,
+  job_overrides: serde_json::Value,
+) -> (RatingOutcome, f64) {
+  let worker = worker(1, json!({ "skills": worker_skills }));
+  let job = job(7, job_overrides);
+  let result =
+    SkillsMatch::new(1.0).determine_rating(&EvaluationContext::new(&worker, &job, &DIAGNOSIS));
+  (result.outcome, result.metrics["matchedSkills"])
+}
+
+#[test]
+fn test_scores_the_fraction_of_listed_skills_matched() {
+  let (outcome, matched) = rate(
+    json!(["Cashier", "Barista"]),
+    json!({ "requiredSkills": ["cashier"], "preferredSkills": ["Barista", "Forklift", "Baker"] }),
+  );
+  assert_eq!(outcome, RatingOutcome::Pass(0.5));
+  assert_eq!(matched, 2.0);
+}
+
+#[test]
+fn test_rejects_missing_required_skills() {
+  let (outcome, _) = rate(
+    json!(["Barista"]),
+    json!({ "requiredSkills": ["Cashier", "Barista"] }),
+  );
+  assert_eq!(
+    outcome,
+    RatingOutcome::Reject(RejectionReason::MissingRequiredSkills(vec![String::from(
+      "Cashier"
+    )]))
+  );
+}
+
+#[test]
+fn test_scores_the_fraction_of_skills_mentioned_in_the_description() {
+  let about = json!({ "about": "Cashier and customer service work" });
+  let (outcome, matched) = rate(
+    json!(["Cashier", "Customer Service", "Barista", "Baker"]),
+    about.clone(),
+  );
+  assert_eq!(outcome, RatingOutcome::Pass(0.5));
+  assert_eq!(matched, 2.0);
+
+  let (outcome, _) = rate(json!(["Cashier"]), about.clone());
+  assert_eq!(outcome, RatingOutcome::Pass(1.0));
+
+  let (outcome, _) = rate(json!(["Barista"]), about.clone());
+  assert_eq!(outcome, RatingOutcome::Pass(0.0));
+
+  let (outcome, _) = rate(json!([]), about);
+  assert_eq!(outcome, RatingOutcome::Pass(0.0));
+}
@@ -0,0 +1,93 @@
+use crate::domain::config::DistanceEvaluatorType;
+use crate::domain::transport::TransportMode;
+use crate::engine::config::{EvaluationConfig, EvaluationContext};
+use crate::engine::match_rating::{MatchRating, RatingOutcome, RatingResult, RejectionReason};
+use crate::engine::registry::build_distance_evaluator;
+use crate::engine::travel_time::TravelTime;
+use crate::fixtures::{job, worker};
+use serde_json::json;
+
+const DIAGNOSIS: EvaluationConfig = EvaluationConfig {
+  with_diagnosis: true,
+  short_circuit_failures: false,
+};
+
+fn rule() -> TravelTime {
+  TravelTime::new(
+    1.0,
+    build_distance_evaluator(&DistanceEvaluatorType::Pythagoras),
+    vec![(TransportMode::Car, 60.0)].into_iter().collect(),
+    20.0,
+    30.0,
+    None,
+  )
+}
+
+fn rate(worker_overrides: serde_json::Value) -> RatingResult {
+  let worker = worker(1, worker_overrides);
+  let job = job(7, json!({}));
+  rule().determine_rating(&EvaluationContext::new(&worker, &job, &DIAGNOSIS))
+}
+
+#[test]
+fn test_scores_commute_against_the_reference_time() {
+  let result = rate(json!({}));
+  let distance = result.metrics["distance"];
+  let commute_minutes = result.metrics["commuteMinutes"];
+  assert_eq!(result.metrics["speedKmh"], 60.0);
+  assert!((commute_minutes - distance).abs() < 1e-9);
+  assert_eq!(
+    result.outcome,
+    RatingOutcome::Pass((30.0 - commute_minutes) / 30.0)
+  );
+}
+
+#[test]
+fn test_uses_the_default_speed_for_unlisted_modes() {
+  let result = rate(json!({ "transportation": "BIKE" }));
+  assert_eq!(result.metrics["speedKmh"], 20.0);
+  assert!((result.metrics["commuteMinutes"] - result.metrics["distance"] * 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_scores_against_the_workers_maximum_commute() {
+  let result = rate(json!({ "maxCommuteMinutes": 20 }));
+  let commute_minutes = result.metrics["commuteMinutes"];
+  assert_eq!(
+    result.outcome,
+    RatingOutcome::Pass((20.0 - commute_minutes) / 20.0)
+  );
+}
+
+#[test]
+fn test_rejects_commutes_longer_than_the_workers_maximum() {
+  let result = rate(json!({ "maxCommuteMinutes": 5 }));
+  assert_eq!(
+    result.outcome,
+    RatingOutcome::Reject(RejectionReason::CommuteTooLong {
+      commute_minutes: result.metrics["commuteMinutes"],
+      max_commute_minutes: 5.0,
+    })
+  );
+}
+
+#[test]
+fn test_rejects_unknown_worker_and_job_locations() {
+  let (mut homeless, mut nowhere) = (worker(1, json!({})), job(7, json!({})));
+  homeless.job_search_address.location = None;
+  nowhere.location = None;
+  let cases = [
+    (&homeless, &job(7, json!({})), true, false),
+    (&worker(1, json!({})), &nowhere, false, true),
+  ];
+  for (worker, job, worker_unknown, job_unknown) in cases.iter() {
+    let result = rule().determine_rating(&EvaluationContext::new(worker, job, &DIAGNOSIS));
+    assert_eq!(
+      result.outcome,
+      RatingOutcome::Reject(RejectionReason::UnknownLocation {
+        worker: *worker_unknown,
+        job: *job_unknown,
+      })
+    );
+  }
+}
@@ -0,0 +1,86 @@
+use crate::engine::config::{EvaluationConfig, EvaluationContext};
+use crate::engine::curve::ScoringCurve;
+use crate::engine::match_rating::{MatchRating, RatingOutcome, RejectionReason};
+use crate::engine::worker_rating::WorkerRating;
+use crate::fixtures::{job, worker};
+use serde_json::json;
+use std::collections::HashMap;
+
+const DIAGNOSIS: EvaluationConfig = EvaluationConfig {
+  with_diagnosis: true,
+  short_circuit_failures: false,
+};
+
+fn rule(company_minimums: &[(&str, u32)], curve: Option<ScoringCurve>) -> WorkerRating {
+  let company_minimum_ratings: HashMap<String, u32> = company_minimums
+    .iter()
+    .map(|(company, minimum)| (String::from(*company), *minimum))
+    .collect();
+  WorkerRating::new(1.0, company_minimum_ratings, curve)
+}
+
+fn rate(rule: &WorkerRating, rating: u32, job_overrides: serde_json::Value) -> RatingOutcome {
+  let worker = worker(1, json!({ "rating": rating }));
+  let job = job(7, job_overrides);
+  rule
+    .determine_rating(&EvaluationContext::new(&worker, &job, &DIAGNOSIS))
+    .outcome
+}
+
+#[test]
+fn test_scores_the_rating_out_of_five() {
+  let rule = rule(&[], None);
+  assert_eq!(rate(&rule, 4, json!({})), RatingOutcome::Pass(0.8));
+  assert_eq!(rate(&rule, 5, json!({})), RatingOutcome::Pass(1.0));
+  assert_eq!(rate(&rule, 0, json!({})), RatingOutcome::Pass(0.0));
+}
+
+#[test]
+fn test_scores_with_a_configured_curve() {
+  let rule = rule(
+    &[],
+    Some(ScoringCurve::Step {
+      threshold: 4.0,
+      below: 0.0,
+      above: 1.0,
+    }),
+  );
+  assert_eq!(rate(&rule, 4, json!({})), RatingOutcome::Pass(1.0));
+  assert_eq!(rate(&rule, 3, json!({})), RatingOutcome::Pass(0.0));
+}
+
+#[test]
+fn test_rejects_ratings_below_the_job_minimum() {
+  let rule = rule(&[], None);
+  assert_eq!(
+    rate(&rule, 3, json!({ "minimumRating": 4 })),
+    RatingOutcome::Reject(RejectionReason::RatingBelowMinimum {
+      rating: 3,
+      minimum_rating: 4
+    })
+  );
+  assert_eq!(
+    rate(&rule, 4, json!({ "minimumRating": 4 })),
+    RatingOutcome::Pass(0.8)
+  );
+}
+
+#[test]
+fn test_falls_back_to_the_company_minimum() {
+  let rule = rule(&[("Acme", 5)], None);
+  assert_eq!(
+    rate(&rule, 4, json!({})),
+    RatingOutcome::Reject(RejectionReason::RatingBelowMinimum {
+      rating: 4,
+      minimum_rating: 5
+    })
+  );
+  assert_eq!(
+    rate(&rule, 4, json!({ "company": "Globex" })),
+    RatingOutcome::Pass(0.8)
+  );
+  assert_eq!(
+    rate(&rule, 2, json!({ "minimumRating": 2 })),
+    RatingOutcome::Pass(0.4)
+  );
+}
@@ -0,0 +1,115 @@
+use super::config::EvaluationContext;
+use super::curve::ScoringCurve;
+use super::distance::GeographicDistanceEvaluator;
+use super::match_rating::{MatchRating, RatingOutcome, RatingResult, RejectionReason};
+use crate::domain::transport::TransportMode;
+use std::collections::HashMap;
+
+pub struct TravelTime {
+  weight_value: f64,
+  distance_evaluator: Box<dyn GeographicDistanceEvaluator + Send + Sync>,
+  speeds_kmh: HashMap<TransportMode, f64>,
+  default_speed_kmh: f64,
+  reference_commute_minutes: f64,
+  curve: Option<ScoringCurve>,
+}
+
+impl TravelTime {
+  pub fn new(
+    weight: f64,
+    distance_evaluator: Box<dyn GeographicDistanceEvaluator + Send + Sync>,
+    speeds_kmh: HashMap<TransportMode, f64>,
+    default_speed_kmh: f64,
+    reference_commute_minutes: f64,
+    curve: Option<ScoringCurve>,
+  ) -> TravelTime {
+    TravelTime {
+      weight_value: weight,
+      distance_evaluator,
+      speeds_kmh,
+      default_speed_kmh,
+      reference_commute_minutes,
+      curve,
+    }
+  }
+}
+
+impl MatchRating for TravelTime {
+  fn get_name(&self) -> &str {
+    "TravelTime"
+  }
+
+  fn get_weight(&self) -> f64 {
+    self.weight_value
+  }
+
+  fn determine_rating(&self, ctx: &EvaluationContext) -> RatingResult {
+    log::debug!(
+      "Running rule {} for Worker {} and Job {}",
+      self.get_name(),
+      ctx.worker.user_id,
+      ctx.job.job_id
+    );
+    let (worker_location, job_location) =
+      match (ctx.worker.job_search_address.location, ctx.job.location) {
+        (Some(w), Some(j)) => (w, j),
+        (w, j) => {
+          log::debug!("Worker or job location is unknown");
+          return RatingResult {
+            outcome: RatingOutcome::Reject(RejectionReason::UnknownLocation {
+              worker: w.is_none(),
+              job: j.is_none(),
+            }),
+            metrics: HashMap::new(),
+          };
+        }
+      };
+    let distance = self
+      .distance_evaluator
+      .determine_distance(&worker_location, &job_location);
+    let speed = self
+      .speeds_kmh
+      .get(&ctx.worker.transportation)
+      .copied()
+      .unwrap_or(self.default_speed_kmh);
+    let commute_minutes = distance / speed * 60.0;
+    let max_commute_minutes = ctx.worker.max_commute_minutes.map(|m| m as f64);
+
+    let mut metrics: HashMap<String, f64> = HashMap::new();
+    if ctx.config.with_diagnosis {
+      metrics.insert(String::from("distance"), distance);
+      metrics.insert(String::from("speedKmh"), speed);
+      metrics.insert(String::from("commuteMinutes"), commute_minutes);
+      if let Some(max_commute_minutes) = max_commute_minutes {
+        metrics.insert(String::from("maxCommuteMinutes"), max_commute_minutes);
+      }
+    }
+
+    if let Some(max_commute_minutes) = max_commute_minutes.filter(|m| commute_minutes > *m) {
+      log::debug!("Commute is longer than the worker's maximum");
+      return RatingResult {
+        outcome: RatingOutcome::Reject(RejectionReason::CommuteTooLong {
+          commute_minutes,
+          max_commute_minutes,
+        }),
+        metrics,
+      };
+    }
+
+    let reference = max_commute_minutes.unwrap_or(self.reference_commute_minutes);
+    let score = match &self.curve {
+      Some(curve) => curve.apply(commute_minutes),
+      None if reference > 0.0 => ((reference - commute_minutes) / reference).max(0.0),
+      None => 0.0,
+    };
+    if ctx.config.with_diagnosis {
+      metrics.insert(String::from("rawValue"), commute_minutes);
+      metrics.insert(String::from("transformedValue"), score);
+    }
+    log::debug!("Rule {} completed with score {}", self.get_name(), score);
+    RatingResult {
+      outcome: RatingOutcome::Pass(score),
+      metrics,
+    }
+  }
+}
@@ -0,0 +1,87 @@
+use super::config::EvaluationContext;
+use super::curve::ScoringCurve;
+use super::match_rating::{MatchRating, RatingOutcome, RatingResult, RejectionReason};
+use std::collections::HashMap;
+
+const MAX_WORKER_RATING: f64 = 5.0;
+
+pub struct WorkerRating {
+  weight_value: f64,
+  company_minimum_ratings: HashMap<String, u32>,
+  curve: ScoringCurve,
+}
+
+impl WorkerRating {
+  pub fn new(
+    weight: f64,
+    company_minimum_ratings: HashMap<String, u32>,
+    curve: Option<ScoringCurve>,
+  ) -> WorkerRating {
+    WorkerRating {
+      weight_value: weight,
+      company_minimum_ratings,
+      curve: curve.unwrap_or(ScoringCurve::Linear {
+        zero: 0.0,
+        one: MAX_WORKER_RATING,
+      }),
+    }
+  }
+}
+
+impl MatchRating for WorkerRating {
+  fn get_name(&self) -> &str {
+    "WorkerRating"
+  }
+
+  fn get_weight(&self) -> f64 {
+    self.weight_value
+  }
+
+  fn determine_rating(&self, ctx: &EvaluationContext) -> RatingResult {
+    log::debug!(
+      "Running rule {} for Worker {} and Job {}",
+      self.get_name(),
+      ctx.worker.user_id,
+      ctx.job.job_id
+    );
+    let minimum_rating = ctx
+      .job
+      .minimum_rating
+      .or_else(|| self.company_minimum_ratings.get(&ctx.job.company).copied());
+
+    let mut metrics: HashMap<String, f64> = HashMap::new();
+    if ctx.config.with_diagnosis {
+      metrics.insert(String::from("rating"), ctx.worker.rating as f64);
+      if let Some(minimum_rating) = minimum_rating {
+        metrics.insert(String::from("minimumRating"), minimum_rating as f64);
+      }
+    }
+
+    if let Some(minimum_rating) = minimum_rating.filter(|m| ctx.worker.rating < *m) {
+      log::debug!(
+        "Worker rating is below the minimum of {}; {} rule failure",
+        minimum_rating,
+        self.get_name()
+      );
+      return RatingResult {
+        outcome: RatingOutcome::Reject(RejectionReason::RatingBelowMinimum {
+          rating: ctx.worker.rating,
+          minimum_rating,
+        }),
+        metrics,
+      };
+    }
+
+    let worker_rating = ctx.worker.rating as f64;
+    let score = self.curve.apply(worker_rating);
+    if ctx.config.with_diagnosis {
+      metrics.insert(String::from("rawValue"), worker_rating);
+      metrics.insert(String::from("transformedValue"), score);
+    }
+    log::debug!("Rule {} completed with score {}", self.get_name(), score);
+    RatingResult {
+      outcome: RatingOutcome::Pass(score),
+      metrics,
+    }
+  }
+}
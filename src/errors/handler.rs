@@ -0,0 +1,22 @@
+use super::bad_request::BadRequestError;
+use super::not_found::NotFoundError;
+use super::server::ServerError;
+use super::unauthorized::UnauthorizedError;
+use warp::http::StatusCode;
+use warp::{Rejection, Reply};
+
+pub async fn handle_rejection(err: Rejection) -> Result<impl Reply, Rejection> {
+  let status = if err.find::<UnauthorizedError>().is_some() {
+    StatusCode::UNAUTHORIZED
+  } else if err.find::<NotFoundError>().is_some() {
+    StatusCode::NOT_FOUND
+  } else if err.find::<BadRequestError>().is_some() {
+    StatusCode::BAD_REQUEST
+  } else if err.find::<ServerError>().is_some() {
+    StatusCode::INTERNAL_SERVER_ERROR
+  } else {
+    return Err(err);
+  };
+
+  Ok(warp::reply::with_status(warp::reply(), status))
+}
@@ -1,2 +1,5 @@
 pub mod server;
 pub mod bad_request;
+pub mod handler;
+pub mod not_found;
+pub mod unauthorized;
@@ -0,0 +1,12 @@
+use warp::reject::Reject;
+
+#[derive(Debug)]
+pub struct NotFoundError {}
+
+impl NotFoundError {
+  pub fn new() -> NotFoundError {
+    NotFoundError {}
+  }
+}
+
+impl Reject for NotFoundError {}
@@ -0,0 +1,12 @@
+use warp::reject::Reject;
+
+#[derive(Debug)]
+pub struct UnauthorizedError {}
+
+impl UnauthorizedError {
+  pub fn new() -> UnauthorizedError {
+    UnauthorizedError {}
+  }
+}
+
+impl Reject for UnauthorizedError {}
@@ -0,0 +1,144 @@
+use crate::domain::config::Config;
+use crate::domain::job::Job;
+use crate::domain::worker::Worker;
+use crate::dto::{JobDto, WorkerDto};
+use crate::errors::server::ServerError;
+use crate::repositories::rest::RestRepository;
+use crate::services::config::ConfigService;
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::convert::TryFrom;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use warp::reject::Rejection;
+
+fn merge(mut base: Value, overrides: Value) -> Value {
+  if let (Some(base), Value::Object(overrides)) = (base.as_object_mut(), overrides) {
+    base.extend(overrides);
+  }
+  base
+}
+
+pub fn worker_dto(user_id: u32, overrides: Value) -> WorkerDto {
+  let base = json!({
+    "guid": format!("w{}", user_id), "userId": user_id, "isActive": true, "phone": "1",
+    "email": "e", "name": { "first": "A", "last": "B" }, "age": 30, "rating": 4,
+    "certificates": [], "skills": [],
+    "jobSearchAddress": { "latitude": "49.78", "longitude": "13.9", "maxJobDistance": 30, "unit": "km" },
+    "transportation": "CAR", "hasDriversLicense": true,
+    "availability": [{ "title": "Sunday", "dayIndex": 7 }],
+  });
+  serde_json::from_value(merge(base, overrides)).unwrap()
+}
+
+pub fn worker(user_id: u32, overrides: Value) -> Worker {
+  Worker::try_from(worker_dto(user_id, overrides)).unwrap()
+}
+
+pub fn job_dto(job_id: u32, overrides: Value) -> JobDto {
+  let base = json!({
+    "jobId": job_id, "guid": format!("j{}", job_id),
+    "location": { "latitude": "49.70", "longitude": "13.90" },
+    "billRate": "$22.50", "workersRequired": 2, "driverLicenseRequired": false,
+    "requiredCertificates": [], "startDate": "2015-11-01T06:00:00.000Z",
+    "about": "Cashier work", "company": "Acme",
+  });
+  serde_json::from_value(merge(base, overrides)).unwrap()
+}
+
+pub fn job(job_id: u32, overrides: Value) -> Job {
+  Job::try_from(job_dto(job_id, overrides)).unwrap()
+}
+
+pub struct StubConfigService {
+  config: Config,
+}
+
+impl StubConfigService {
+  pub fn new(overrides: Value) -> StubConfigService {
+    let base = json!({
+      "app_name": "jobmatching", "jobs_to_return": 3, "workers_to_return": 5,
+      "repository": { "type": "file", "directory": "resources" }, "rules": [],
+    });
+    StubConfigService {
+      config: serde_json::from_value(merge(base, overrides)).unwrap(),
+    }
+  }
+}
+
+impl ConfigService for StubConfigService {
+  fn get_config(&self) -> &Config {
+    &self.config
+  }
+}
+
+pub struct StubRepository {
+  workers: Mutex<Vec<Worker>>,
+  jobs: Mutex<Vec<Job>>,
+  fetches: AtomicUsize,
+  failing: AtomicBool,
+  available: AtomicBool,
+}
+
+impl StubRepository {
+  pub fn new(workers: Vec<Worker>, jobs: Vec<Job>) -> StubRepository {
+    StubRepository {
+      workers: Mutex::new(workers),
+      jobs: Mutex::new(jobs),
+      fetches: AtomicUsize::new(0),
+      failing: AtomicBool::new(false),
+      available: AtomicBool::new(true),
+    }
+  }
+
+  pub fn fetch_count(&self) -> usize {
+    self.fetches.load(Ordering::SeqCst)
+  }
+
+  pub fn set_failing(&self, failing: bool) {
+    self.failing.store(failing, Ordering::SeqCst);
+  }
+
+  pub fn set_available(&self, available: bool) {
+    self.available.store(available, Ordering::SeqCst);
+  }
+
+  pub fn set_workers(&self, workers: Vec<Worker>) {
+    *self.workers.lock().unwrap() = workers;
+  }
+
+  async fn fetch<T: Clone>(&self, items: &Mutex<Vec<T>>) -> Result<Vec<T>, Rejection> {
+    self.fetches.fetch_add(1, Ordering::SeqCst);
+    tokio::time::delay_for(Duration::from_millis(5)).await;
+    if self.failing.load(Ordering::SeqCst) {
+      return Err(warp::reject::custom(ServerError::new()));
+    }
+    Ok(items.lock().unwrap().clone())
+  }
+}
+
+#[async_trait]
+impl RestRepository for StubRepository {
+  async fn find_all_workers(&self) -> Result<Vec<Worker>, Rejection> {
+    self.fetch(&self.workers).await
+  }
+
+  async fn find_worker_by_id(&self, worker_id: u32) -> Result<Option<Worker>, Rejection> {
+    let workers = self.fetch(&self.workers).await?;
+    Ok(workers.into_iter().find(|w| w.user_id == worker_id))
+  }
+
+  async fn find_all_jobs(&self) -> Result<Vec<Job>, Rejection> {
+    self.fetch(&self.jobs).await
+  }
+
+  async fn find_job_by_id(&self, job_id: u32) -> Result<Option<Job>, Rejection> {
+    let jobs = self.fetch(&self.jobs).await?;
+    Ok(jobs.into_iter().find(|j| j.job_id == job_id))
+  }
+
+  fn is_available(&self) -> bool {
+    self.available.load(Ordering::SeqCst)
+  }
+}
@@ -3,40 +3,130 @@ mod domain;
 mod dto;
 mod engine;
 mod errors;
+#[cfg(test)]
+mod fixtures;
 mod repositories;
 mod routes;
 mod services;
 
-use domain::config::RatingWeights;
-use engine::available_on_start_day::AvailableOnStartDay;
-use engine::can_drive::CanDrive;
-use engine::distance::PythagorasDistanceEvaluator;
-use engine::job_location::JobLocation;
-use engine::job_positons::JobPositions;
-use engine::match_rating::MatchRating;
-use engine::pay_rate::PayRate;
-use engine::required_certificates::HasRequiredCertificates;
+use domain::config::{Config, HttpConfig, RepositoryConfig};
+use engine::registry::build_match_ratings;
 use log::LevelFilter;
-use repositories::rest::RestRepositoryImpl;
+use repositories::caching::CachingRestRepository;
+use repositories::coalescing::CoalescingRestRepository;
+use repositories::data_quality::{DataQualityService, DataQualityServiceImpl};
+use repositories::file::FileRepository;
+use repositories::resilience::{CircuitBreaker, ResilientClient, RetryPolicy};
+use repositories::rest::{RestRepository, RestRepositoryImpl};
+use repositories::sqlite::SqliteRepository;
+use repositories::store::RecordStore;
 use services::config::{ConfigService, FileConfigService};
+use services::ingestion::IngestionServiceImpl;
 use services::job_match::JobMatchServiceImpl;
 use services::rules::RulesServiceImpl;
 use services::worker_match::WorkerMatchServiceImpl;
 use simple_logger::SimpleLogger;
 use std::sync::Arc;
+use std::time::Duration;
 
-fn build_match_ratings(weights: &RatingWeights) -> Vec<Box<dyn MatchRating + Send + Sync>> {
-    vec![
-        Box::new(AvailableOnStartDay::new(weights.available_on_start_days)),
-        Box::new(HasRequiredCertificates::new(weights.required_certificates)),
-        Box::new(JobLocation::new(
-            weights.job_location,
-            Box::new(PythagorasDistanceEvaluator::new()),
+fn build_http_client(config: &HttpConfig) -> ResilientClient {
+    ResilientClient::new(
+        Duration::from_millis(config.timeout_ms),
+        config.max_concurrent_requests,
+        RetryPolicy::new(
+            config.max_retries,
+            Duration::from_millis(config.initial_backoff_ms),
+            Duration::from_millis(config.max_backoff_ms),
+        ),
+        CircuitBreaker::new(
+            config.circuit_breaker_threshold,
+            Duration::from_millis(config.circuit_breaker_reset_ms),
+        ),
+    )
+}
+
+async fn build_repository(
+    config: &Config,
+    data_quality: Arc<dyn DataQualityService + Send + Sync>,
+) -> Result<
+    (
+        Arc<dyn RestRepository + Send + Sync>,
+        Option<Arc<dyn RecordStore + Send + Sync>>,
+    ),
+    String,
+> {
+    match &config.repository {
+        RepositoryConfig::Rest { base_url, http } => Ok((
+            Arc::new(CoalescingRestRepository::new(Arc::new(
+                RestRepositoryImpl::new(
+                    base_url.clone(),
+                    build_http_client(http),
+                    Duration::from_millis(http.lookup_index_max_age_ms),
+                    data_quality,
+                ),
+            ))),
+            None,
         )),
-        Box::new(PayRate::new(weights.pay_rate)),
-        Box::new(CanDrive::new()),
-        Box::new(JobPositions::new(weights.job_positions)),
-    ]
+        RepositoryConfig::File {
+            directory,
+            watch_interval_seconds,
+        } => {
+            let repository = Arc::new(FileRepository::new(directory.clone(), data_quality));
+            repository.load().await?;
+            if let Some(interval) = watch_interval_seconds {
+                repository.start_watching(Duration::from_secs(*interval));
+            }
+            Ok((repository.clone(), Some(repository)))
+        }
+        RepositoryConfig::Sqlite { path, .. } => {
+            let repository = Arc::new(
+                SqliteRepository::open(path, data_quality)
+                    .map_err(|e| format!("Could not open SQLite database {}: {}", path, e))?,
+            );
+            Ok((repository.clone(), Some(repository)))
+        }
+    }
+}
+
+async fn import_data(config: &Config) -> Result<(), String> {
+    let (path, upstream_url, http) = match &config.repository {
+        RepositoryConfig::Sqlite {
+            path,
+            upstream_url,
+            http,
+        } => (path, upstream_url, http),
+        _ => {
+            return Err(String::from(
+                "The import command requires a sqlite repository to be configured",
+            ))
+        }
+    };
+    let data_quality = Arc::new(DataQualityServiceImpl::new());
+    let upstream = RestRepositoryImpl::new(
+        upstream_url.clone(),
+        build_http_client(http),
+        Duration::from_millis(http.lookup_index_max_age_ms),
+        data_quality.clone(),
+    );
+    let (workers, jobs) = tokio::join!(upstream.find_all_workers(), upstream.find_all_jobs());
+    let workers = workers.map_err(|e| format!("Could not load workers from upstream: {:?}", e))?;
+    let jobs = jobs.map_err(|e| format!("Could not load jobs from upstream: {:?}", e))?;
+    SqliteRepository::open(path, data_quality.clone())
+        .map_err(|e| format!("Could not open SQLite database {}: {}", path, e))?
+        .import(workers, jobs)
+        .await
+        .map_err(|e| format!("Could not import data into SQLite: {:?}", e))?;
+    for (dataset, report) in data_quality.get_report() {
+        log::info!(
+            "Imported {} of {} {} records ({} issues)",
+            report.records - report.skipped,
+            report.records,
+            dataset,
+            report.issues.len()
+        );
+    }
+
+    Ok(())
 }
 
 #[tokio::main]
@@ -49,12 +139,56 @@ async fn main() {
         .unwrap();
     let mut config_service = FileConfigService::new();
     config_service.load_config("resources/config.json").unwrap();
-    let rest_repository = Arc::new(RestRepositoryImpl::new(
-        config_service.get_config().base_url.clone(),
-    ));
-    let rules_service = Arc::new(RulesServiceImpl::new(build_match_ratings(
-        &config_service.get_config().weights,
-    )));
+    if std::env::args().nth(1).as_deref() == Some("import") {
+        if let Err(e) = import_data(config_service.get_config()).await {
+            log::error!("Could not import data: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+    let data_quality_service = Arc::new(DataQualityServiceImpl::new());
+    let (rest_repository, local_store) =
+        match build_repository(config_service.get_config(), data_quality_service.clone()).await {
+            Ok(repository) => repository,
+            Err(e) => {
+                log::error!("Could not initialise the repository: {}", e);
+                std::process::exit(1);
+            }
+        };
+    let caching_repository = config_service.get_config().cache.as_ref().map(|c| {
+        let repository = Arc::new(CachingRestRepository::new(
+            rest_repository.clone(),
+            Duration::from_secs(c.ttl_seconds),
+            Duration::from_secs(c.max_staleness_seconds),
+            Duration::from_secs(c.refresh_interval_seconds),
+        ));
+        repository.start_refresh();
+        repository
+    });
+    let rest_repository: Arc<dyn RestRepository + Send + Sync> = match &caching_repository {
+        Some(c) => c.clone(),
+        None => rest_repository,
+    };
+    let ingestion_service = config_service.get_config().webhook.as_ref().map(|_| {
+        let mut stores: Vec<Arc<dyn RecordStore + Send + Sync>> = local_store.into_iter().collect();
+        if let Some(c) = &caching_repository {
+            stores.push(c.clone());
+        }
+        if stores.is_empty() {
+            panic!("Webhooks require a cache or a file/sqlite repository to store pushed records");
+        }
+        Arc::new(IngestionServiceImpl::new(stores))
+    });
+    let match_ratings = match build_match_ratings(config_service.get_config()) {
+        Ok(match_ratings) => match_ratings,
+        Err(errors) => {
+            for error in &errors {
+                log::error!("Invalid rule configuration: {}", error);
+            }
+            panic!("Found {} invalid rule configuration entries", errors.len());
+        }
+    };
+    let rules_service = Arc::new(RulesServiceImpl::new(match_ratings));
     let job_match_service = Arc::new(JobMatchServiceImpl::new(
         rules_service.clone(),
         rest_repository.clone(),
@@ -71,6 +205,9 @@ async fn main() {
         job_match_service,
         worker_match_service,
         Arc::new(config_service),
+        caching_repository,
+        data_quality_service,
+        ingestion_service,
     ))
     .run(([127, 0, 0, 1], 3030))
     .await;
@@ -0,0 +1,343 @@
+use super::index::{IdIndex, Identifiable};
+use super::rest::{Fetched, RestRepository};
+use super::store::{PushedRecords, RecordStore};
+use crate::domain::job::Job;
+use crate::domain::worker::Worker;
+use crate::dto::{CacheDatasetStatusDto, CacheStatusDto};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::time::Instant;
+use warp::reject::Rejection;
+
+pub trait CacheStatusService {
+  fn get_cache_status(&self) -> CacheStatusDto;
+}
+
+struct Snapshot<T> {
+  index: Arc<IdIndex<T>>,
+  loaded_at: Instant,
+}
+
+struct CachedDataset<T> {
+  snapshot: RwLock<Option<Snapshot<T>>>,
+  last_refresh: RwLock<Option<DateTime<Utc>>>,
+  last_refresh_error: RwLock<Option<String>>,
+  hits: AtomicU64,
+  misses: AtomicU64,
+  pushed: PushedRecords<T>,
+}
+
+impl<T: Identifiable + Clone> CachedDataset<T> {
+  fn new() -> CachedDataset<T> {
+    CachedDataset {
+      snapshot: RwLock::new(None),
+      last_refresh: RwLock::new(None),
+      last_refresh_error: RwLock::new(None),
+      hits: AtomicU64::new(0),
+      misses: AtomicU64::new(0),
+      pushed: PushedRecords::new(),
+    }
+  }
+
+  fn get_fresh(&self, ttl: Duration) -> Option<Arc<IdIndex<T>>> {
+    let snapshot = self.snapshot.read().unwrap();
+    let index = snapshot
+      .as_ref()
+      .filter(|s| s.loaded_at.elapsed() <= ttl)
+      .map(|s| s.index.clone());
+    match index {
+      Some(_) => self.hits.fetch_add(1, Ordering::Relaxed),
+      None => self.misses.fetch_add(1, Ordering::Relaxed),
+    };
+
+    index
+  }
+
+  fn get_stale(&self, max_staleness: Duration) -> Option<Arc<IdIndex<T>>> {
+    let snapshot = self.snapshot.read().unwrap();
+    snapshot
+      .as_ref()
+      .filter(|s| s.loaded_at.elapsed() <= max_staleness)
+      .map(|s| s.index.clone())
+  }
+
+  fn store(&self, items: Vec<T>, checkpoint: u64) -> Arc<IdIndex<T>> {
+    let mut index = IdIndex::new(items);
+    let mut snapshot = self.snapshot.write().unwrap();
+    self.pushed.apply(&mut index, checkpoint);
+    let index = Arc::new(index);
+    *snapshot = Some(Snapshot {
+      index: index.clone(),
+      loaded_at: Instant::now(),
+    });
+    drop(snapshot);
+    *self.last_refresh.write().unwrap() = Some(Utc::now());
+    *self.last_refresh_error.write().unwrap() = None;
+
+    index
+  }
+
+  fn update<F, R>(&self, f: F) -> Option<R>
+  where
+    F: FnOnce(&mut IdIndex<T>) -> R,
+  {
+    let mut snapshot = self.snapshot.write().unwrap();
+    let snapshot = snapshot.as_mut()?;
+    let mut index = (*snapshot.index).clone();
+    let result = f(&mut index);
+    snapshot.index = Arc::new(index);
+
+    Some(result)
+  }
+
+  fn record_error(&self, error: String) {
+    *self.last_refresh_error.write().unwrap() = Some(error);
+  }
+
+  fn get_status(&self, ttl: Duration) -> CacheDatasetStatusDto {
+    let snapshot = self.snapshot.read().unwrap();
+    CacheDatasetStatusDto {
+      size: snapshot.as_ref().map(|s| s.index.len()).unwrap_or(0),
+      age_seconds: snapshot.as_ref().map(|s| s.loaded_at.elapsed().as_secs()),
+      last_refresh: *self.last_refresh.read().unwrap(),
+      last_refresh_error: self.last_refresh_error.read().unwrap().clone(),
+      hits: self.hits.load(Ordering::Relaxed),
+      misses: self.misses.load(Ordering::Relaxed),
+      stale: snapshot
+        .as_ref()
+        .map_or(false, |s| s.loaded_at.elapsed() > ttl),
+      duplicate_ids: snapshot
+        .as_ref()
+        .map(|s| s.index.get_duplicate_ids().to_vec())
+        .unwrap_or_default(),
+      duplicate_guids: snapshot
+        .as_ref()
+        .map(|s| s.index.get_duplicate_guids().to_vec())
+        .unwrap_or_default(),
+    }
+  }
+}
+
+pub struct CachingRestRepository {
+  delegate: Arc<dyn RestRepository + Send + Sync>,
+  ttl: Duration,
+  max_staleness: Duration,
+  refresh_interval: Duration,
+  workers: CachedDataset<Worker>,
+  jobs: CachedDataset<Job>,
+}
+
+impl CachingRestRepository {
+  pub fn new(
+    delegate: Arc<dyn RestRepository + Send + Sync>,
+    ttl: Duration,
+    max_staleness: Duration,
+    refresh_interval: Duration,
+  ) -> CachingRestRepository {
+    CachingRestRepository {
+      delegate,
+      ttl,
+      max_staleness,
+      refresh_interval,
+      workers: CachedDataset::new(),
+      jobs: CachedDataset::new(),
+    }
+  }
+
+  pub fn start_refresh(self: &Arc<Self>) {
+    let repository = self.clone();
+    log::info!(
+      "Refreshing cached data every {}s",
+      self.refresh_interval.as_secs()
+    );
+    tokio::spawn(async move {
+      let mut interval = tokio::time::interval(repository.refresh_interval);
+      loop {
+        interval.tick().await;
+        repository.refresh().await;
+      }
+    });
+  }
+
+  async fn refresh(&self) {
+    log::debug!("Refreshing cached workers & jobs");
+    let (workers, jobs) = tokio::join!(self.load_workers(), self.load_jobs());
+    if workers.is_err() || jobs.is_err() {
+      log::warn!("Cache refresh failed; serving previously loaded data until it becomes too stale");
+    }
+  }
+
+  async fn load_workers(&self) -> Result<Arc<IdIndex<Worker>>, Rejection> {
+    let checkpoint = self.workers.pushed.checkpoint();
+    match self.delegate.find_all_workers().await {
+      Ok(workers) => Ok(self.workers.store(workers, checkpoint)),
+      Err(e) => {
+        self.workers.record_error(format!("{:?}", e));
+        Err(e)
+      }
+    }
+  }
+
+  async fn load_jobs(&self) -> Result<Arc<IdIndex<Job>>, Rejection> {
+    let checkpoint = self.jobs.pushed.checkpoint();
+    match self.delegate.find_all_jobs().await {
+      Ok(jobs) => Ok(self.jobs.store(jobs, checkpoint)),
+      Err(e) => {
+        self.jobs.record_error(format!("{:?}", e));
+        Err(e)
+      }
+    }
+  }
+
+  async fn get_workers(&self) -> Result<Fetched<Arc<IdIndex<Worker>>>, Rejection> {
+    if let Some(workers) = self.workers.get_fresh(self.ttl) {
+      return Ok(Fetched::fresh(workers));
+    }
+    log::debug!("Worker cache miss");
+    if !self.delegate.is_available() {
+      if let Some(workers) = self.workers.get_stale(self.max_staleness) {
+        log::warn!("Upstream circuit open; serving stale worker data");
+        return Ok(Fetched::new(workers, true));
+      }
+    }
+    match self.load_workers().await {
+      Ok(workers) => Ok(Fetched::fresh(workers)),
+      Err(e) => match self.workers.get_stale(self.max_staleness) {
+        Some(workers) => {
+          log::warn!("Upstream unavailable; serving stale worker data");
+          Ok(Fetched::new(workers, true))
+        }
+        None => Err(e),
+      },
+    }
+  }
+
+  async fn get_jobs(&self) -> Result<Fetched<Arc<IdIndex<Job>>>, Rejection> {
+    if let Some(jobs) = self.jobs.get_fresh(self.ttl) {
+      return Ok(Fetched::fresh(jobs));
+    }
+    log::debug!("Job cache miss");
+    if !self.delegate.is_available() {
+      if let Some(jobs) = self.jobs.get_stale(self.max_staleness) {
+        log::warn!("Upstream circuit open; serving stale job data");
+        return Ok(Fetched::new(jobs, true));
+      }
+    }
+    match self.load_jobs().await {
+      Ok(jobs) => Ok(Fetched::fresh(jobs)),
+      Err(e) => match self.jobs.get_stale(self.max_staleness) {
+        Some(jobs) => {
+          log::warn!("Upstream unavailable; serving stale job data");
+          Ok(Fetched::new(jobs, true))
+        }
+        None => Err(e),
+      },
+    }
+  }
+}
+
+impl CacheStatusService for CachingRestRepository {
+  fn get_cache_status(&self) -> CacheStatusDto {
+    CacheStatusDto {
+      enabled: true,
+      ttl_seconds: self.ttl.as_secs(),
+      max_staleness_seconds: self.max_staleness.as_secs(),
+      workers: Some(self.workers.get_status(self.ttl)),
+      jobs: Some(self.jobs.get_status(self.ttl)),
+    }
+  }
+}
+
+#[async_trait]
+impl RestRepository for CachingRestRepository {
+  async fn find_all_workers(&self) -> Result<Vec<Worker>, Rejection> {
+    Ok(self.fetch_all_workers().await?.value)
+  }
+
+  async fn find_worker_by_id(&self, worker_id: u32) -> Result<Option<Worker>, Rejection> {
+    Ok(self.fetch_worker_by_id(worker_id).await?.value)
+  }
+
+  async fn find_all_jobs(&self) -> Result<Vec<Job>, Rejection> {
+    Ok(self.fetch_all_jobs().await?.value)
+  }
+
+  async fn find_job_by_id(&self, job_id: u32) -> Result<Option<Job>, Rejection> {
+    Ok(self.fetch_job_by_id(job_id).await?.value)
+  }
+
+  async fn fetch_all_workers(&self) -> Result<Fetched<Vec<Worker>>, Rejection> {
+    Ok(self.get_workers().await?.map(|workers| workers.to_vec()))
+  }
+
+  async fn fetch_worker_by_id(&self, worker_id: u32) -> Result<Fetched<Option<Worker>>, Rejection> {
+    let worker = self
+      .get_workers()
+      .await?
+      .map(|workers| workers.get(worker_id).cloned());
+    if worker.value.is_none() {
+      log::warn!("Could not find worker {}", worker_id);
+    }
+
+    Ok(worker)
+  }
+
+  async fn fetch_all_jobs(&self) -> Result<Fetched<Vec<Job>>, Rejection> {
+    Ok(self.get_jobs().await?.map(|jobs| jobs.to_vec()))
+  }
+
+  async fn fetch_job_by_id(&self, job_id: u32) -> Result<Fetched<Option<Job>>, Rejection> {
+    let job = self.get_jobs().await?.map(|jobs| jobs.get(job_id).cloned());
+    if job.value.is_none() {
+      log::warn!("Could not find Job {}", job_id);
+    }
+
+    Ok(job)
+  }
+
+  fn is_available(&self) -> bool {
+    self.delegate.is_available()
+  }
+}
+
+#[async_trait]
+impl RecordStore for CachingRestRepository {
+  async fn upsert_worker(&self, worker: Worker) -> Result<(), Rejection> {
+    self.get_workers().await?;
+    self
+      .workers
+      .update(|index| self.workers.pushed.upsert(index, worker));
+    Ok(())
+  }
+
+  async fn delete_worker(&self, worker_id: u32) -> Result<bool, Rejection> {
+    self.get_workers().await?;
+    Ok(
+      self
+        .workers
+        .update(|index| self.workers.pushed.remove(index, worker_id))
+        .unwrap_or(false),
+    )
+  }
+
+  async fn upsert_job(&self, job: Job) -> Result<(), Rejection> {
+    self.get_jobs().await?;
+    self
+      .jobs
+      .update(|index| self.jobs.pushed.upsert(index, job));
+    Ok(())
+  }
+
+  async fn delete_job(&self, job_id: u32) -> Result<bool, Rejection> {
+    self.get_jobs().await?;
+    Ok(
+      self
+        .jobs
+        .update(|index| self.jobs.pushed.remove(index, job_id))
+        .unwrap_or(false),
+    )
+  }
+}
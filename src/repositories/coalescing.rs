@@ -0,0 +1,153 @@
+use super::index::{IdIndex, Identifiable};
+use super::rest::RestRepository;
+use crate::domain::job::Job;
+use crate::domain::worker::Worker;
+use crate::errors::server::ServerError;
+use async_trait::async_trait;
+use futures::future::{BoxFuture, FutureExt, Shared};
+use std::sync::{Arc, Mutex};
+use warp::reject::Rejection;
+
+type SharedFetch<T> = Shared<BoxFuture<'static, Result<Arc<IdIndex<T>>, ()>>>;
+
+struct FlightState<T> {
+  next_id: u64,
+  in_flight: Option<(u64, SharedFetch<T>)>,
+}
+
+struct SingleFlight<T> {
+  state: Mutex<FlightState<T>>,
+}
+
+impl<T: Identifiable + Clone + Send + Sync + 'static> SingleFlight<T> {
+  fn new() -> SingleFlight<T> {
+    SingleFlight {
+      state: Mutex::new(FlightState {
+        next_id: 0,
+        in_flight: None,
+      }),
+    }
+  }
+
+  async fn run<F>(&self, fetch: F) -> Result<Arc<IdIndex<T>>, Rejection>
+  where
+    F: FnOnce() -> BoxFuture<'static, Result<Arc<IdIndex<T>>, Rejection>>,
+  {
+    let (id, shared) = {
+      let mut state = self.state.lock().unwrap();
+      match &state.in_flight {
+        Some((id, shared)) => {
+          log::debug!("Joining in-flight {} request", T::entity_name());
+          (*id, shared.clone())
+        }
+        None => {
+          state.next_id += 1;
+          let shared = fetch().map(|r| r.map_err(|_| ())).boxed().shared();
+          state.in_flight = Some((state.next_id, shared.clone()));
+          (state.next_id, shared)
+        }
+      }
+    };
+
+    let result = shared.await;
+    let mut state = self.state.lock().unwrap();
+    if let Some((current_id, _)) = &state.in_flight {
+      if *current_id == id {
+        state.in_flight = None;
+      }
+    }
+
+    result.map_err(|_| warp::reject::custom(ServerError::new()))
+  }
+}
+
+pub struct CoalescingRestRepository {
+  delegate: Arc<dyn RestRepository + Send + Sync>,
+  workers: SingleFlight<Worker>,
+  jobs: SingleFlight<Job>,
+}
+
+impl CoalescingRestRepository {
+  pub fn new(delegate: Arc<dyn RestRepository + Send + Sync>) -> CoalescingRestRepository {
+    CoalescingRestRepository {
+      delegate,
+      workers: SingleFlight::new(),
+      jobs: SingleFlight::new(),
+    }
+  }
+
+  async fn load_workers(&self) -> Result<Arc<IdIndex<Worker>>, Rejection> {
+    let delegate = self.delegate.clone();
+    self
+      .workers
+      .run(move || async move { delegate.find_worker_index().await }.boxed())
+      .await
+  }
+
+  async fn load_jobs(&self) -> Result<Arc<IdIndex<Job>>, Rejection> {
+    let delegate = self.delegate.clone();
+    self
+      .jobs
+      .run(move || async move { delegate.find_job_index().await }.boxed())
+      .await
+  }
+}
+
+#[async_trait]
+impl RestRepository for CoalescingRestRepository {
+  async fn find_all_workers(&self) -> Result<Vec<Worker>, Rejection> {
+    Ok(self.load_workers().await?.to_vec())
+  }
+
+  async fn find_worker_by_id(&self, worker_id: u32) -> Result<Option<Worker>, Rejection> {
+    let index = match self.delegate.loaded_worker_index() {
+      Some(index) => index,
+      None => self.load_workers().await?,
+    };
+    let worker = index.get(worker_id).cloned();
+    match worker {
+      Some(_) => log::debug!("Worker {} found", worker_id),
+      None => log::warn!("Could not find worker {}", worker_id),
+    }
+
+    Ok(worker)
+  }
+
+  async fn find_all_jobs(&self) -> Result<Vec<Job>, Rejection> {
+    Ok(self.load_jobs().await?.to_vec())
+  }
+
+  async fn find_job_by_id(&self, job_id: u32) -> Result<Option<Job>, Rejection> {
+    let index = match self.delegate.loaded_job_index() {
+      Some(index) => index,
+      None => self.load_jobs().await?,
+    };
+    let job = index.get(job_id).cloned();
+    match job {
+      Some(_) => log::debug!("Job {} found", job_id),
+      None => log::warn!("Could not find Job {}", job_id),
+    }
+
+    Ok(job)
+  }
+
+  async fn find_worker_index(&self) -> Result<Arc<IdIndex<Worker>>, Rejection> {
+    self.load_workers().await
+  }
+
+  async fn find_job_index(&self) -> Result<Arc<IdIndex<Job>>, Rejection> {
+    self.load_jobs().await
+  }
+
+  fn loaded_worker_index(&self) -> Option<Arc<IdIndex<Worker>>> {
+    self.delegate.loaded_worker_index()
+  }
+
+  fn loaded_job_index(&self) -> Option<Arc<IdIndex<Job>>> {
+    self.delegate.loaded_job_index()
+  }
+
+  fn is_available(&self) -> bool {
+    self.delegate.is_available()
+  }
+}
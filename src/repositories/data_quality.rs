@@ -0,0 +1,42 @@
+use crate::dto::DataQualityDatasetDto;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+pub trait DataQualityService {
+  fn record_dataset(&self, dataset: &str, report: DataQualityDatasetDto);
+  fn get_report(&self) -> HashMap<String, DataQualityDatasetDto>;
+}
+
+pub struct DataQualityServiceImpl {
+  datasets: RwLock<HashMap<String, DataQualityDatasetDto>>,
+}
+
+impl DataQualityServiceImpl {
+  pub fn new() -> DataQualityServiceImpl {
+    DataQualityServiceImpl {
+      datasets: RwLock::new(HashMap::new()),
+    }
+  }
+}
+
+impl DataQualityService for DataQualityServiceImpl {
+  fn record_dataset(&self, dataset: &str, report: DataQualityDatasetDto) {
+    if report.skipped > 0 {
+      log::warn!(
+        "Skipped {} of {} {} records due to data-quality issues",
+        report.skipped,
+        report.records,
+        dataset
+      );
+    }
+    self
+      .datasets
+      .write()
+      .unwrap()
+      .insert(String::from(dataset), report);
+  }
+
+  fn get_report(&self) -> HashMap<String, DataQualityDatasetDto> {
+    self.datasets.read().unwrap().clone()
+  }
+}
@@ -0,0 +1,255 @@
+use super::data_quality::DataQualityService;
+use super::index::IdIndex;
+use super::parsing::{Ingest, ParsedRecords};
+use super::rest::RestRepository;
+use super::store::{PushedRecords, RecordStore};
+use crate::domain::job::Job;
+use crate::domain::worker::Worker;
+use crate::errors::server::ServerError;
+use async_trait::async_trait;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime};
+use warp::reject::Rejection;
+
+struct LoadedFile<T> {
+  path: PathBuf,
+  modified: Option<SystemTime>,
+  index: Arc<IdIndex<T>>,
+}
+
+struct FileDataset<T> {
+  name: &'static str,
+  loaded: RwLock<Option<LoadedFile<T>>>,
+  pushed: PushedRecords<T>,
+}
+
+impl<T: Ingest + Clone> FileDataset<T> {
+  fn new(name: &'static str) -> FileDataset<T> {
+    FileDataset {
+      name,
+      loaded: RwLock::new(None),
+      pushed: PushedRecords::new(),
+    }
+  }
+
+  fn resolve_path(&self, directory: &Path) -> PathBuf {
+    let json_path = directory.join(format!("{}.json", self.name));
+    let ndjson_path = directory.join(format!("{}.ndjson", self.name));
+    if !json_path.exists() && ndjson_path.exists() {
+      ndjson_path
+    } else {
+      json_path
+    }
+  }
+
+  async fn load(
+    &self,
+    directory: &Path,
+    data_quality: &(dyn DataQualityService + Send + Sync),
+  ) -> Result<(), String> {
+    let checkpoint = self.pushed.checkpoint();
+    let path = self.resolve_path(directory);
+    log::info!("Loading {} from {}", self.name, path.display());
+    let read_error = |e: std::io::Error| format!("Could not read {}: {}", path.display(), e);
+    let modified = tokio::fs::metadata(&path)
+      .await
+      .map_err(read_error)?
+      .modified()
+      .ok();
+    let content = tokio::fs::read_to_string(&path).await.map_err(read_error)?;
+    let values: Vec<Result<Value, String>> = if path.extension().map_or(false, |e| e == "ndjson") {
+      content
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(n, l)| serde_json::from_str(l).map_err(|e| format!("Line {}: {}", n + 1, e)))
+        .collect()
+    } else {
+      serde_json::from_str::<Vec<Value>>(&content)
+        .map_err(|e| format!("Could not parse {}: {}", path.display(), e))?
+        .into_iter()
+        .map(Ok)
+        .collect()
+    };
+    let parsed: ParsedRecords<T> = ParsedRecords::parse(values);
+    data_quality.record_dataset(self.name, parsed.get_report());
+    log::info!("Loaded {} {} from file", parsed.index.len(), self.name);
+
+    let mut index = parsed.index;
+    let mut loaded = self.loaded.write().unwrap();
+    self.pushed.apply(&mut index, checkpoint);
+    *loaded = Some(LoadedFile {
+      path,
+      modified,
+      index: Arc::new(index),
+    });
+    Ok(())
+  }
+
+  async fn has_changed(&self, directory: &Path) -> bool {
+    let path = self.resolve_path(directory);
+    let modified = match tokio::fs::metadata(&path).await {
+      Ok(m) => m.modified().ok(),
+      Err(_) => return false,
+    };
+    match &*self.loaded.read().unwrap() {
+      Some(l) => l.path != path || l.modified != modified,
+      None => true,
+    }
+  }
+
+  fn update<F, R>(&self, f: F) -> Result<R, Rejection>
+  where
+    F: FnOnce(&mut IdIndex<T>) -> R,
+  {
+    match &mut *self.loaded.write().unwrap() {
+      Some(l) => {
+        let mut index = (*l.index).clone();
+        let result = f(&mut index);
+        l.index = Arc::new(index);
+        Ok(result)
+      }
+      None => {
+        log::error!("No {} have been loaded from file", self.name);
+        Err(warp::reject::custom(ServerError::new()))
+      }
+    }
+  }
+
+  fn get_index(&self) -> Result<Arc<IdIndex<T>>, Rejection> {
+    match &*self.loaded.read().unwrap() {
+      Some(l) => Ok(l.index.clone()),
+      None => {
+        log::error!("No {} have been loaded from file", self.name);
+        Err(warp::reject::custom(ServerError::new()))
+      }
+    }
+  }
+}
+
+pub struct FileRepository {
+  directory: PathBuf,
+  data_quality: Arc<dyn DataQualityService + Send + Sync>,
+  workers: FileDataset<Worker>,
+  jobs: FileDataset<Job>,
+}
+
+impl FileRepository {
+  pub fn new(
+    directory: String,
+    data_quality: Arc<dyn DataQualityService + Send + Sync>,
+  ) -> FileRepository {
+    FileRepository {
+      directory: PathBuf::from(directory),
+      data_quality,
+      workers: FileDataset::new("workers"),
+      jobs: FileDataset::new("jobs"),
+    }
+  }
+
+  pub async fn load(&self) -> Result<(), String> {
+    self
+      .workers
+      .load(&self.directory, self.data_quality.as_ref())
+      .await?;
+    self
+      .jobs
+      .load(&self.directory, self.data_quality.as_ref())
+      .await
+  }
+
+  pub fn start_watching(self: &Arc<Self>, interval: Duration) {
+    let repository = self.clone();
+    log::info!(
+      "Watching {} for changes every {}s",
+      self.directory.display(),
+      interval.as_secs()
+    );
+    tokio::spawn(async move {
+      let mut interval = tokio::time::interval(interval);
+      loop {
+        interval.tick().await;
+        repository.reload_changed().await;
+      }
+    });
+  }
+
+  async fn reload_changed(&self) {
+    if self.workers.has_changed(&self.directory).await {
+      if let Err(e) = self
+        .workers
+        .load(&self.directory, self.data_quality.as_ref())
+        .await
+      {
+        log::error!("Error reloading workers file: {}", e);
+      }
+    }
+    if self.jobs.has_changed(&self.directory).await {
+      if let Err(e) = self
+        .jobs
+        .load(&self.directory, self.data_quality.as_ref())
+        .await
+      {
+        log::error!("Error reloading jobs file: {}", e);
+      }
+    }
+  }
+}
+
+#[async_trait]
+impl RestRepository for FileRepository {
+  async fn find_all_workers(&self) -> Result<Vec<Worker>, Rejection> {
+    Ok(self.workers.get_index()?.to_vec())
+  }
+
+  async fn find_worker_by_id(&self, worker_id: u32) -> Result<Option<Worker>, Rejection> {
+    let worker = self.workers.get_index()?.get(worker_id).cloned();
+    if worker.is_none() {
+      log::warn!("Could not find worker {}", worker_id);
+    }
+
+    Ok(worker)
+  }
+
+  async fn find_all_jobs(&self) -> Result<Vec<Job>, Rejection> {
+    Ok(self.jobs.get_index()?.to_vec())
+  }
+
+  async fn find_job_by_id(&self, job_id: u32) -> Result<Option<Job>, Rejection> {
+    let job = self.jobs.get_index()?.get(job_id).cloned();
+    if job.is_none() {
+      log::warn!("Could not find Job {}", job_id);
+    }
+
+    Ok(job)
+  }
+}
+
+#[async_trait]
+impl RecordStore for FileRepository {
+  async fn upsert_worker(&self, worker: Worker) -> Result<(), Rejection> {
+    self
+      .workers
+      .update(|index| self.workers.pushed.upsert(index, worker))
+  }
+
+  async fn delete_worker(&self, worker_id: u32) -> Result<bool, Rejection> {
+    self
+      .workers
+      .update(|index| self.workers.pushed.remove(index, worker_id))
+  }
+
+  async fn upsert_job(&self, job: Job) -> Result<(), Rejection> {
+    self
+      .jobs
+      .update(|index| self.jobs.pushed.upsert(index, job))
+  }
+
+  async fn delete_job(&self, job_id: u32) -> Result<bool, Rejection> {
+    self
+      .jobs
+      .update(|index| self.jobs.pushed.remove(index, job_id))
+  }
+}
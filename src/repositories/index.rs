@@ -0,0 +1,133 @@
+use crate::domain::job::Job;
+use crate::domain::worker::Worker;
+use std::collections::{HashMap, HashSet};
+
+pub trait Identifiable {
+  fn entity_name() -> &'static str;
+  fn dataset_name() -> &'static str;
+  fn id_field() -> &'static str;
+  fn get_id(&self) -> u32;
+  fn get_guid(&self) -> &str;
+}
+
+impl Identifiable for Worker {
+  fn entity_name() -> &'static str {
+    "Worker"
+  }
+
+  fn dataset_name() -> &'static str {
+    "workers"
+  }
+
+  fn id_field() -> &'static str {
+    "userId"
+  }
+
+  fn get_id(&self) -> u32 {
+    self.user_id
+  }
+
+  fn get_guid(&self) -> &str {
+    &self.guid
+  }
+}
+
+impl Identifiable for Job {
+  fn entity_name() -> &'static str {
+    "Job"
+  }
+
+  fn dataset_name() -> &'static str {
+    "jobs"
+  }
+
+  fn id_field() -> &'static str {
+    "jobId"
+  }
+
+  fn get_id(&self) -> u32 {
+    self.job_id
+  }
+
+  fn get_guid(&self) -> &str {
+    &self.guid
+  }
+}
+
+#[derive(Clone)]
+pub struct IdIndex<T> {
+  ids: Vec<u32>,
+  items: HashMap<u32, T>,
+  duplicate_ids: Vec<u32>,
+  duplicate_guids: Vec<String>,
+}
+
+impl<T: Identifiable + Clone> IdIndex<T> {
+  pub fn new(entries: Vec<T>) -> IdIndex<T> {
+    let mut index = IdIndex {
+      ids: Vec::with_capacity(entries.len()),
+      items: HashMap::with_capacity(entries.len()),
+      duplicate_ids: Vec::new(),
+      duplicate_guids: Vec::new(),
+    };
+    let mut guids: HashSet<String> = HashSet::with_capacity(entries.len());
+    for entry in entries {
+      let id = entry.get_id();
+      if index.items.contains_key(&id) {
+        log::warn!(
+          "Duplicate {} id {} found; keeping first occurrence",
+          T::entity_name(),
+          id
+        );
+        index.duplicate_ids.push(id);
+        continue;
+      }
+      if !guids.insert(String::from(entry.get_guid())) {
+        log::warn!(
+          "Duplicate {} guid {} found on id {}",
+          T::entity_name(),
+          entry.get_guid(),
+          id
+        );
+        index.duplicate_guids.push(String::from(entry.get_guid()));
+      }
+      index.ids.push(id);
+      index.items.insert(id, entry);
+    }
+
+    index
+  }
+
+  pub fn get(&self, id: u32) -> Option<&T> {
+    self.items.get(&id)
+  }
+
+  pub fn len(&self) -> usize {
+    self.ids.len()
+  }
+
+  pub fn to_vec(&self) -> Vec<T> {
+    self.ids.iter().map(|id| self.items[id].clone()).collect()
+  }
+
+  pub fn upsert(&mut self, entry: T) {
+    let id = entry.get_id();
+    if self.items.insert(id, entry).is_none() {
+      self.ids.push(id);
+    }
+  }
+
+  pub fn remove(&mut self, id: u32) -> Option<T> {
+    let entry = self.items.remove(&id)?;
+    self.ids.retain(|i| *i != id);
+    Some(entry)
+  }
+
+  pub fn get_duplicate_ids(&self) -> &[u32] {
+    &self.duplicate_ids
+  }
+
+  pub fn get_duplicate_guids(&self) -> &[String] {
+    &self.duplicate_guids
+  }
+}
@@ -1 +1,13 @@
-pub mod rest;
\ No newline at end of file
+pub mod caching;
+pub mod coalescing;
+pub mod data_quality;
+pub mod file;
+pub mod index;
+pub mod parsing;
+pub mod resilience;
+pub mod rest;
+pub mod sqlite;
+pub mod store;
+
+#[cfg(test)]
+mod tests;
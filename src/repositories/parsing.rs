@@ -0,0 +1,151 @@
+use super::index::{IdIndex, Identifiable};
+use crate::domain::job::Job;
+use crate::domain::worker::Worker;
+use crate::dto::{DataQualityDatasetDto, DataQualityIssueDto, JobDto, WorkerDto};
+use chrono::Utc;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+pub trait Ingest: Identifiable + Sized {
+  type Dto: DeserializeOwned;
+
+  /// Returns the record with any non-fatal warnings, or the problems that
+  /// make it unusable.
+  fn from_dto(dto: Self::Dto) -> Result<(Self, Vec<String>), Vec<String>>;
+}
+
+impl Ingest for Worker {
+  type Dto = WorkerDto;
+
+  fn from_dto(dto: WorkerDto) -> Result<(Worker, Vec<String>), Vec<String>> {
+    Worker::parse(dto)
+  }
+}
+
+impl Ingest for Job {
+  type Dto = JobDto;
+
+  fn from_dto(dto: JobDto) -> Result<(Job, Vec<String>), Vec<String>> {
+    Job::parse(dto)
+  }
+}
+
+pub struct ParsedRecords<T> {
+  pub index: IdIndex<T>,
+  records: usize,
+  issues: Vec<DataQualityIssueDto>,
+}
+
+impl<T: Ingest + Clone> ParsedRecords<T> {
+  pub fn parse(values: Vec<Result<Value, String>>) -> ParsedRecords<T> {
+    let records = values.len();
+    let mut issues = Vec::new();
+    let mut entries = Vec::with_capacity(records);
+    for value in values {
+      let value = match value {
+        Ok(v) => v,
+        Err(e) => {
+          log::warn!("Skipping malformed {} record: {}", T::entity_name(), e);
+          issues.push(DataQualityIssueDto {
+            id: None,
+            guid: None,
+            kind: String::from("parse_error"),
+            message: e,
+            skipped: true,
+          });
+          continue;
+        }
+      };
+      let id = value
+        .get(T::id_field())
+        .and_then(|v| v.as_u64())
+        .map(|v| v as u32);
+      let guid = value.get("guid").and_then(|v| v.as_str()).map(String::from);
+      let dto = match serde_json::from_value::<T::Dto>(value) {
+        Ok(dto) => dto,
+        Err(e) => {
+          log::warn!(
+            "Skipping {} {:?} that could not be parsed: {}",
+            T::entity_name(),
+            id,
+            e
+          );
+          issues.push(DataQualityIssueDto {
+            id,
+            guid,
+            kind: String::from("parse_error"),
+            message: e.to_string(),
+            skipped: true,
+          });
+          continue;
+        }
+      };
+      match T::from_dto(dto) {
+        Ok((entry, warnings)) => {
+          for warning in warnings {
+            log::warn!("{} {:?}: {}", T::entity_name(), id, warning);
+            issues.push(DataQualityIssueDto {
+              id,
+              guid: guid.clone(),
+              kind: String::from("invalid_field"),
+              message: warning,
+              skipped: false,
+            });
+          }
+          entries.push(entry);
+        }
+        Err(problems) => {
+          let message = problems.join("; ");
+          log::warn!(
+            "Skipping {} {:?} with invalid fields: {}",
+            T::entity_name(),
+            id,
+            message
+          );
+          issues.push(DataQualityIssueDto {
+            id,
+            guid,
+            kind: String::from("invalid_field"),
+            message,
+            skipped: true,
+          });
+        }
+      }
+    }
+
+    let index = IdIndex::new(entries);
+    for id in index.get_duplicate_ids() {
+      issues.push(DataQualityIssueDto {
+        id: Some(*id),
+        guid: None,
+        kind: String::from("duplicate_id"),
+        message: String::from("Duplicate id; only the first record was kept"),
+        skipped: true,
+      });
+    }
+    for guid in index.get_duplicate_guids() {
+      issues.push(DataQualityIssueDto {
+        id: None,
+        guid: Some(guid.clone()),
+        kind: String::from("duplicate_guid"),
+        message: String::from("Duplicate guid"),
+        skipped: false,
+      });
+    }
+
+    ParsedRecords {
+      index,
+      records,
+      issues,
+    }
+  }
+
+  pub fn get_report(&self) -> DataQualityDatasetDto {
+    DataQualityDatasetDto {
+      checked_at: Utc::now(),
+      records: self.records,
+      skipped: self.issues.iter().filter(|i| i.skipped).count(),
+      issues: self.issues.clone(),
+    }
+  }
+}
@@ -0,0 +1,219 @@
+use rand::Rng;
+use reqwest::{Client, StatusCode};
+use serde::de::DeserializeOwned;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+
+#[derive(Debug)]
+pub enum RequestError {
+  CircuitOpen,
+  Transport(reqwest::Error),
+  Status(StatusCode),
+  Parse(reqwest::Error),
+}
+
+impl RequestError {
+  fn is_retryable(&self) -> bool {
+    match self {
+      RequestError::Transport(_) => true,
+      RequestError::Status(s) => s.is_server_error() || *s == StatusCode::TOO_MANY_REQUESTS,
+      _ => false,
+    }
+  }
+}
+
+pub struct RetryPolicy {
+  max_retries: u32,
+  initial_backoff: Duration,
+  max_backoff: Duration,
+}
+
+impl RetryPolicy {
+  pub fn new(max_retries: u32, initial_backoff: Duration, max_backoff: Duration) -> RetryPolicy {
+    RetryPolicy {
+      max_retries,
+      initial_backoff,
+      max_backoff,
+    }
+  }
+
+  fn backoff(&self, attempt: u32) -> Duration {
+    let backoff = self
+      .initial_backoff
+      .checked_mul(2u32.saturating_pow(attempt))
+      .unwrap_or(self.max_backoff)
+      .min(self.max_backoff);
+    let millis = backoff.as_millis() as u64;
+    if millis < 2 {
+      return backoff;
+    }
+
+    Duration::from_millis(rand::thread_rng().gen_range(millis / 2, millis + 1))
+  }
+}
+
+struct BreakerState {
+  consecutive_failures: u32,
+  opened_at: Option<Instant>,
+  trial_started: Option<Instant>,
+}
+
+pub struct CircuitBreaker {
+  failure_threshold: u32,
+  reset_timeout: Duration,
+  state: Mutex<BreakerState>,
+}
+
+impl CircuitBreaker {
+  pub fn new(failure_threshold: u32, reset_timeout: Duration) -> CircuitBreaker {
+    CircuitBreaker {
+      failure_threshold,
+      reset_timeout,
+      state: Mutex::new(BreakerState {
+        consecutive_failures: 0,
+        opened_at: None,
+        trial_started: None,
+      }),
+    }
+  }
+
+  /// Whether requests are currently being rejected without reaching upstream.
+  fn is_open(&self) -> bool {
+    let state = self.state.lock().unwrap();
+    let trial_pending = state
+      .trial_started
+      .map_or(false, |started| started.elapsed() < self.reset_timeout);
+    match state.opened_at {
+      None => false,
+      Some(opened_at) => opened_at.elapsed() < self.reset_timeout || trial_pending,
+    }
+  }
+
+  /// A trial that has not reported back within `reset_timeout` (e.g. because
+  /// its future was dropped) is considered abandoned and another is allowed.
+  fn try_acquire(&self) -> bool {
+    let mut state = self.state.lock().unwrap();
+    let trial_expired = state
+      .trial_started
+      .map_or(true, |started| started.elapsed() >= self.reset_timeout);
+    match state.opened_at {
+      None => true,
+      Some(opened_at) if opened_at.elapsed() >= self.reset_timeout && trial_expired => {
+        log::info!("Circuit breaker half-open; allowing trial request");
+        state.trial_started = Some(Instant::now());
+        true
+      }
+      Some(_) => false,
+    }
+  }
+
+  fn record_success(&self) {
+    let mut state = self.state.lock().unwrap();
+    if state.opened_at.is_some() {
+      log::info!("Circuit breaker closed");
+    }
+    state.consecutive_failures = 0;
+    state.opened_at = None;
+    state.trial_started = None;
+  }
+
+  /// Ends a request that says nothing about upstream health (e.g. a 4xx),
+  /// leaving the breaker state and failure count untouched.
+  fn release(&self) {
+    self.state.lock().unwrap().trial_started = None;
+  }
+
+  fn record_failure(&self) {
+    let mut state = self.state.lock().unwrap();
+    state.consecutive_failures += 1;
+    state.trial_started = None;
+    if state.opened_at.is_some() || state.consecutive_failures >= self.failure_threshold {
+      if state.opened_at.is_none() {
+        log::warn!(
+          "Circuit breaker opened after {} consecutive failures",
+          state.consecutive_failures
+        );
+      }
+      state.opened_at = Some(Instant::now());
+    }
+  }
+}
+
+pub struct ResilientClient {
+  client: Client,
+  request_limiter: Semaphore,
+  retry_policy: RetryPolicy,
+  circuit_breaker: CircuitBreaker,
+}
+
+impl ResilientClient {
+  pub fn new(
+    timeout: Duration,
+    max_concurrent_requests: usize,
+    retry_policy: RetryPolicy,
+    circuit_breaker: CircuitBreaker,
+  ) -> ResilientClient {
+    ResilientClient {
+      client: Client::builder().timeout(timeout).build().unwrap(),
+      request_limiter: Semaphore::new(max_concurrent_requests),
+      retry_policy,
+      circuit_breaker,
+    }
+  }
+
+  pub fn is_circuit_open(&self) -> bool {
+    self.circuit_breaker.is_open()
+  }
+
+  pub async fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<T, RequestError> {
+    let mut attempt = 0;
+    loop {
+      if !self.circuit_breaker.try_acquire() {
+        log::warn!("Circuit breaker open; not requesting {}", url);
+        return Err(RequestError::CircuitOpen);
+      }
+
+      match self.send(url).await {
+        Ok(value) => {
+          self.circuit_breaker.record_success();
+          return Ok(value);
+        }
+        Err(e) => {
+          if !e.is_retryable() {
+            self.circuit_breaker.release();
+            return Err(e);
+          }
+          self.circuit_breaker.record_failure();
+          if attempt >= self.retry_policy.max_retries {
+            return Err(e);
+          }
+          let backoff = self.retry_policy.backoff(attempt);
+          log::warn!(
+            "Request to {} failed ({:?}); retrying in {}ms",
+            url,
+            e,
+            backoff.as_millis()
+          );
+          tokio::time::delay_for(backoff).await;
+          attempt += 1;
+        }
+      }
+    }
+  }
+
+  async fn send<T: DeserializeOwned>(&self, url: &str) -> Result<T, RequestError> {
+    let _permit = self.request_limiter.acquire().await;
+    let response = self
+      .client
+      .get(url)
+      .send()
+      .await
+      .map_err(RequestError::Transport)?;
+    if !response.status().is_success() {
+      return Err(RequestError::Status(response.status()));
+    }
+
+    response.json().await.map_err(RequestError::Parse)
+  }
+}
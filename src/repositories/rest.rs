@@ -1,95 +1,219 @@
-use crate::dto::{JobDto, WorkerDto};
+use super::data_quality::DataQualityService;
+use super::index::IdIndex;
+use super::parsing::{Ingest, ParsedRecords};
+use super::resilience::ResilientClient;
+use crate::domain::job::Job;
+use crate::domain::worker::Worker;
 use crate::errors::server::ServerError;
 use async_trait::async_trait;
+use serde_json::Value;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 use warp::reject::Rejection;
 
 #[async_trait]
 pub trait RestRepository {
-  async fn find_all_workers(&self) -> std::result::Result<Vec<WorkerDto>, Rejection>;
-  async fn find_worker_by_id(&self, worker_id: u32) -> Result<Option<WorkerDto>, Rejection>;
-  async fn find_all_jobs(&self) -> Result<Vec<JobDto>, Rejection>;
-  async fn find_job_by_id(&self, job_id: u32) -> Result<Option<JobDto>, Rejection>;
+  async fn find_all_workers(&self) -> std::result::Result<Vec<Worker>, Rejection>;
+  async fn find_worker_by_id(&self, worker_id: u32) -> Result<Option<Worker>, Rejection>;
+  async fn find_all_jobs(&self) -> Result<Vec<Job>, Rejection>;
+  async fn find_job_by_id(&self, job_id: u32) -> Result<Option<Job>, Rejection>;
+
+  async fn fetch_all_workers(&self) -> Result<Fetched<Vec<Worker>>, Rejection> {
+    Ok(Fetched::fresh(self.find_all_workers().await?))
+  }
+
+  async fn fetch_worker_by_id(&self, worker_id: u32) -> Result<Fetched<Option<Worker>>, Rejection> {
+    Ok(Fetched::fresh(self.find_worker_by_id(worker_id).await?))
+  }
+
+  async fn fetch_all_jobs(&self) -> Result<Fetched<Vec<Job>>, Rejection> {
+    Ok(Fetched::fresh(self.find_all_jobs().await?))
+  }
+
+  async fn fetch_job_by_id(&self, job_id: u32) -> Result<Fetched<Option<Job>>, Rejection> {
+    Ok(Fetched::fresh(self.find_job_by_id(job_id).await?))
+  }
+
+  /// Fetches every worker, indexed by id.
+  async fn find_worker_index(&self) -> Result<Arc<IdIndex<Worker>>, Rejection> {
+    Ok(Arc::new(IdIndex::new(self.find_all_workers().await?)))
+  }
+
+  /// Fetches every job, indexed by id.
+  async fn find_job_index(&self) -> Result<Arc<IdIndex<Job>>, Rejection> {
+    Ok(Arc::new(IdIndex::new(self.find_all_jobs().await?)))
+  }
+
+  /// The worker index already held for id lookups, if it is still fresh.
+  fn loaded_worker_index(&self) -> Option<Arc<IdIndex<Worker>>> {
+    None
+  }
+
+  /// The job index already held for id lookups, if it is still fresh.
+  fn loaded_job_index(&self) -> Option<Arc<IdIndex<Job>>> {
+    None
+  }
+
+  /// Whether the upstream source is currently accepting requests.
+  fn is_available(&self) -> bool {
+    true
+  }
 }
 
-pub struct RestRepositoryImpl {
-  base_url: String,
+/// Data returned by a repository, flagged when it was served from a snapshot
+/// older than the cache TTL because the upstream could not be reached.
+pub struct Fetched<T> {
+  pub value: T,
+  pub stale: bool,
 }
 
-impl RestRepositoryImpl {
-  pub fn new(base_url: String) -> RestRepositoryImpl {
-    RestRepositoryImpl { base_url }
+impl<T> Fetched<T> {
+  pub fn new(value: T, stale: bool) -> Fetched<T> {
+    Fetched { value, stale }
+  }
+
+  pub fn fresh(value: T) -> Fetched<T> {
+    Fetched::new(value, false)
+  }
+
+  pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Fetched<U> {
+    Fetched::new(f(self.value), self.stale)
   }
 }
 
-#[async_trait]
-impl RestRepository for RestRepositoryImpl {
-  async fn find_all_workers(&self) -> Result<Vec<WorkerDto>, Rejection> {
-    let path = format!("{}/{}", self.base_url, "workers");
-    let response = reqwest::get(&path).await;
-    if let Err(e) = response {
-      log::error!("Error retrieving data from Rest resource {:?}", e);
-      return Err(warp::reject::custom(ServerError::new()));
-    }
-    let workers = response.unwrap().json().await;
+struct LookupIndex<T> {
+  loaded: RwLock<Option<(Instant, Arc<IdIndex<T>>)>>,
+}
 
-    match workers {
-      Ok(w) => Ok(w),
-      Err(e) => {
-        log::error!("Error parsing worker data {:?}", e);
-        Err(warp::reject::custom(ServerError::new()))
-      }
+impl<T> LookupIndex<T> {
+  fn new() -> LookupIndex<T> {
+    LookupIndex {
+      loaded: RwLock::new(None),
     }
   }
 
-  async fn find_worker_by_id(&self, worker_id: u32) -> Result<Option<WorkerDto>, Rejection> {
-    let mut matching_workers: Vec<WorkerDto> = self
-      .find_all_workers()
-      .await?
-      .into_iter()
-      .filter(|w| w.user_id == worker_id)
-      .collect();
-
-    if matching_workers.len() > 0 {
-      log::debug!("Worker {} found", worker_id);
-      Ok(Some(matching_workers.swap_remove(0)))
-    } else {
-      log::warn!("Could not find worker {}", worker_id);
-      Ok(None)
+  fn get(&self, max_age: Duration) -> Option<Arc<IdIndex<T>>> {
+    match &*self.loaded.read().unwrap() {
+      Some((loaded_at, index)) if loaded_at.elapsed() <= max_age => Some(index.clone()),
+      _ => None,
     }
   }
 
-  async fn find_all_jobs(&self) -> Result<Vec<JobDto>, Rejection> {
-    let path = format!("{}/{}", self.base_url, "jobs");
-    let response = reqwest::get(&path).await;
-    if let Err(e) = response {
-      log::error!("Error retrieving data from Rest resource {:?}", e);
-      return Err(warp::reject::custom(ServerError::new()));
+  fn store(&self, index: IdIndex<T>) -> Arc<IdIndex<T>> {
+    let index = Arc::new(index);
+    *self.loaded.write().unwrap() = Some((Instant::now(), index.clone()));
+    index
+  }
+}
+
+pub struct RestRepositoryImpl {
+  base_url: String,
+  client: ResilientClient,
+  index_max_age: Duration,
+  data_quality: Arc<dyn DataQualityService + Send + Sync>,
+  workers: LookupIndex<Worker>,
+  jobs: LookupIndex<Job>,
+}
+
+impl RestRepositoryImpl {
+  pub fn new(
+    base_url: String,
+    client: ResilientClient,
+    index_max_age: Duration,
+    data_quality: Arc<dyn DataQualityService + Send + Sync>,
+  ) -> RestRepositoryImpl {
+    RestRepositoryImpl {
+      base_url,
+      client,
+      index_max_age,
+      data_quality,
+      workers: LookupIndex::new(),
+      jobs: LookupIndex::new(),
     }
+  }
 
-    let jobs = response.unwrap().json().await;
-    match jobs {
-      Ok(j) => Ok(j),
+  async fn load<T>(&self, lookup: &LookupIndex<T>) -> Result<Arc<IdIndex<T>>, Rejection>
+  where
+    T: Ingest + Clone,
+  {
+    let path = format!("{}/{}", self.base_url, T::dataset_name());
+    match self.client.get_json::<Vec<Value>>(&path).await {
+      Ok(values) => {
+        let parsed: ParsedRecords<T> = ParsedRecords::parse(values.into_iter().map(Ok).collect());
+        self
+          .data_quality
+          .record_dataset(T::dataset_name(), parsed.get_report());
+        Ok(lookup.store(parsed.index))
+      }
       Err(e) => {
-        log::error!("Error parsing jobs data {:?}", e);
+        log::error!(
+          "Error retrieving {} data from Rest resource {:?}",
+          T::dataset_name(),
+          e
+        );
         Err(warp::reject::custom(ServerError::new()))
       }
     }
   }
 
-  async fn find_job_by_id(&self, job_id: u32) -> Result<Option<JobDto>, Rejection> {
-    let mut matching_jobs: Vec<JobDto> = self
-      .find_all_jobs()
-      .await?
-      .into_iter()
-      .filter(|j| j.job_id == job_id)
-      .collect();
-
-    if matching_jobs.len() > 0 {
-      log::debug!("Job {} found", job_id);
-      Ok(Some(matching_jobs.swap_remove(0)))
-    } else {
-      log::warn!("Could not find Job {}", job_id);
-      Ok(None)
+  async fn lookup<T>(&self, lookup: &LookupIndex<T>) -> Result<Arc<IdIndex<T>>, Rejection>
+  where
+    T: Ingest + Clone,
+  {
+    match lookup.get(self.index_max_age) {
+      Some(index) => Ok(index),
+      None => self.load(lookup).await,
     }
   }
 }
+
+#[async_trait]
+impl RestRepository for RestRepositoryImpl {
+  async fn find_all_workers(&self) -> Result<Vec<Worker>, Rejection> {
+    Ok(self.load(&self.workers).await?.to_vec())
+  }
+
+  async fn find_worker_by_id(&self, worker_id: u32) -> Result<Option<Worker>, Rejection> {
+    let worker = self.lookup(&self.workers).await?.get(worker_id).cloned();
+    match worker {
+      Some(_) => log::debug!("Worker {} found", worker_id),
+      None => log::warn!("Could not find worker {}", worker_id),
+    }
+
+    Ok(worker)
+  }
+
+  async fn find_all_jobs(&self) -> Result<Vec<Job>, Rejection> {
+    Ok(self.load(&self.jobs).await?.to_vec())
+  }
+
+  async fn find_job_by_id(&self, job_id: u32) -> Result<Option<Job>, Rejection> {
+    let job = self.lookup(&self.jobs).await?.get(job_id).cloned();
+    match job {
+      Some(_) => log::debug!("Job {} found", job_id),
+      None => log::warn!("Could not find Job {}", job_id),
+    }
+
+    Ok(job)
+  }
+
+  async fn find_worker_index(&self) -> Result<Arc<IdIndex<Worker>>, Rejection> {
+    self.load(&self.workers).await
+  }
+
+  async fn find_job_index(&self) -> Result<Arc<IdIndex<Job>>, Rejection> {
+    self.load(&self.jobs).await
+  }
+
+  fn loaded_worker_index(&self) -> Option<Arc<IdIndex<Worker>>> {
+    self.workers.get(self.index_max_age)
+  }
+
+  fn loaded_job_index(&self) -> Option<Arc<IdIndex<Job>>> {
+    self.jobs.get(self.index_max_age)
+  }
+
+  fn is_available(&self) -> bool {
+    !self.client.is_circuit_open()
+  }
+}
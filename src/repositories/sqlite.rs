@@ -0,0 +1,616 @@
+use super::data_quality::DataQualityService;
+use super::index::Identifiable;
+use super::parsing::{Ingest, ParsedRecords};
+use super::rest::RestRepository;
+use super::store::RecordStore;
+use crate::domain::job::Job;
+use crate::domain::worker::Worker;
+use crate::dto::{
+  DateRangeDto, DayDto, GeographicAreaDto, GeographicLocationDto, JobDto, NameDto, WorkerDto,
+};
+use crate::errors::server::ServerError;
+use async_trait::async_trait;
+use chrono::DateTime;
+use rusqlite::types::Type;
+use rusqlite::{params, Connection, Row};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use warp::reject::Rejection;
+
+const MIGRATIONS: &[&str] = &[
+  r#"
+  CREATE TABLE workers (
+    user_id INTEGER PRIMARY KEY,
+    guid TEXT NOT NULL,
+    is_active INTEGER NOT NULL,
+    phone TEXT NOT NULL,
+    email TEXT NOT NULL,
+    first_name TEXT NOT NULL,
+    last_name TEXT NOT NULL,
+    age INTEGER NOT NULL,
+    rating INTEGER NOT NULL,
+    search_latitude TEXT NOT NULL,
+    search_longitude TEXT NOT NULL,
+    max_job_distance REAL NOT NULL,
+    distance_unit TEXT NOT NULL,
+    transportation TEXT NOT NULL,
+    has_drivers_license INTEGER NOT NULL
+  );
+  CREATE INDEX workers_guid ON workers (guid);
+
+  CREATE TABLE worker_certificates (
+    user_id INTEGER NOT NULL REFERENCES workers (user_id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    certificate TEXT,
+    PRIMARY KEY (user_id, position)
+  );
+
+  CREATE TABLE worker_skills (
+    user_id INTEGER NOT NULL REFERENCES workers (user_id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    skill TEXT NOT NULL,
+    PRIMARY KEY (user_id, position)
+  );
+
+  CREATE TABLE worker_availability (
+    user_id INTEGER NOT NULL REFERENCES workers (user_id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    title TEXT,
+    day_index INTEGER,
+    PRIMARY KEY (user_id, position)
+  );
+
+  CREATE TABLE jobs (
+    job_id INTEGER PRIMARY KEY,
+    guid TEXT NOT NULL,
+    latitude TEXT NOT NULL,
+    longitude TEXT NOT NULL,
+    bill_rate TEXT NOT NULL,
+    workers_required INTEGER NOT NULL,
+    driver_license_required INTEGER NOT NULL,
+    start_date TEXT NOT NULL,
+    about TEXT NOT NULL,
+    company TEXT NOT NULL
+  );
+  CREATE INDEX jobs_guid ON jobs (guid);
+
+  CREATE TABLE job_certificates (
+    job_id INTEGER NOT NULL REFERENCES jobs (job_id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    certificate TEXT NOT NULL,
+    PRIMARY KEY (job_id, position)
+  );
+"#,
+  r#"
+  CREATE TABLE job_skills (
+    job_id INTEGER NOT NULL REFERENCES jobs (job_id) ON DELETE CASCADE,
+    required INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    skill TEXT NOT NULL,
+    PRIMARY KEY (job_id, required, position)
+  );
+"#,
+  r#"
+  ALTER TABLE jobs ADD COLUMN minimum_rating INTEGER;
+"#,
+  r#"
+  ALTER TABLE workers ADD COLUMN max_commute_minutes INTEGER;
+"#,
+  r#"
+  ALTER TABLE jobs ADD COLUMN timezone TEXT;
+"#,
+  r#"
+  ALTER TABLE jobs ADD COLUMN schedule TEXT;
+"#,
+  r#"
+  ALTER TABLE worker_availability ADD COLUMN start_time TEXT;
+  ALTER TABLE worker_availability ADD COLUMN end_time TEXT;
+"#,
+  r#"
+  CREATE TABLE worker_blackout_dates (
+    user_id INTEGER NOT NULL REFERENCES workers (user_id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    start_date TEXT NOT NULL,
+    end_date TEXT NOT NULL,
+    PRIMARY KEY (user_id, position)
+  );
+"#,
+];
+
+pub struct SqliteRepository {
+  connection: Arc<Mutex<Connection>>,
+  data_quality: Arc<dyn DataQualityService + Send + Sync>,
+}
+
+impl SqliteRepository {
+  pub fn open(
+    path: &str,
+    data_quality: Arc<dyn DataQualityService + Send + Sync>,
+  ) -> rusqlite::Result<SqliteRepository> {
+    log::info!("Opening SQLite database {}", path);
+    let mut connection = Connection::open(path)?;
+    connection.pragma_update(None, "foreign_keys", &true)?;
+    migrate(&mut connection)?;
+
+    Ok(SqliteRepository {
+      connection: Arc::new(Mutex::new(connection)),
+      data_quality,
+    })
+  }
+
+  pub async fn import(&self, workers: Vec<Worker>, jobs: Vec<Job>) -> Result<(), Rejection> {
+    self
+      .with_connection(move |c| {
+        let tx = c.transaction()?;
+        tx.execute_batch("DELETE FROM workers; DELETE FROM jobs;")?;
+        for worker in &workers {
+          insert_worker(&tx, &WorkerDto::from(worker))?;
+        }
+        for job in &jobs {
+          insert_job(&tx, &JobDto::from(job))?;
+        }
+        log::info!("Imported {} workers and {} jobs", workers.len(), jobs.len());
+        tx.commit()
+      })
+      .await
+  }
+
+  async fn with_connection<F, R>(&self, f: F) -> Result<R, Rejection>
+  where
+    F: FnOnce(&mut Connection) -> rusqlite::Result<R> + Send + 'static,
+    R: Send + 'static,
+  {
+    let connection = self.connection.clone();
+    let result = tokio::task::spawn_blocking(move || f(&mut connection.lock().unwrap())).await;
+    match result {
+      Ok(Ok(r)) => Ok(r),
+      Ok(Err(e)) => {
+        log::error!("Error querying SQLite database {:?}", e);
+        Err(warp::reject::custom(ServerError::new()))
+      }
+      Err(e) => {
+        log::error!("SQLite task failed {:?}", e);
+        Err(warp::reject::custom(ServerError::new()))
+      }
+    }
+  }
+}
+
+fn parse<T>(dtos: Vec<T::Dto>) -> ParsedRecords<T>
+where
+  T: Ingest + Clone,
+  T::Dto: Serialize,
+{
+  ParsedRecords::parse(
+    dtos
+      .iter()
+      .map(|dto| serde_json::to_value(dto).map_err(|e| e.to_string()))
+      .collect(),
+  )
+}
+
+fn migrate(connection: &mut Connection) -> rusqlite::Result<()> {
+  let version: i64 = connection.pragma_query_value(None, "user_version", |r| r.get(0))?;
+  for (idx, migration) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+    log::info!("Applying SQLite migration {}", idx + 1);
+    let tx = connection.transaction()?;
+    tx.execute_batch(migration)?;
+    tx.pragma_update(None, "user_version", &((idx + 1) as i64))?;
+    tx.commit()?;
+  }
+
+  Ok(())
+}
+
+fn insert_worker(connection: &Connection, worker: &WorkerDto) -> rusqlite::Result<()> {
+  connection.execute(
+    "INSERT INTO workers (user_id, guid, is_active, phone, email, first_name, last_name, age,
+      rating, search_latitude, search_longitude, max_job_distance, distance_unit, transportation,
+      has_drivers_license, max_commute_minutes)
+      VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)",
+    params![
+      worker.user_id,
+      worker.guid,
+      worker.active,
+      worker.phone,
+      worker.email,
+      worker.name.first,
+      worker.name.last,
+      worker.age,
+      worker.rating,
+      worker.job_search_address.latitude,
+      worker.job_search_address.longitude,
+      worker.job_search_address.max_job_distance,
+      worker.job_search_address.unit,
+      worker.transportation,
+      worker.has_drivers_license,
+      worker.max_commute_minutes,
+    ],
+  )?;
+  for (position, certificate) in worker.certificates.iter().enumerate() {
+    connection.execute(
+      "INSERT INTO worker_certificates (user_id, position, certificate) VALUES (?1, ?2, ?3)",
+      params![worker.user_id, position as i64, certificate],
+    )?;
+  }
+  for (position, skill) in worker.skills.iter().enumerate() {
+    connection.execute(
+      "INSERT INTO worker_skills (user_id, position, skill) VALUES (?1, ?2, ?3)",
+      params![worker.user_id, position as i64, skill],
+    )?;
+  }
+  for (position, day) in worker.availability.iter().enumerate() {
+    connection.execute(
+      "INSERT INTO worker_availability (user_id, position, title, day_index, start_time,
+        end_time) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+      params![
+        worker.user_id,
+        position as i64,
+        day.as_ref().map(|d| &d.title),
+        day.as_ref().map(|d| d.day_index),
+        day.as_ref().and_then(|d| d.start_time.as_ref()),
+        day.as_ref().and_then(|d| d.end_time.as_ref()),
+      ],
+    )?;
+  }
+  for (position, range) in worker.blackout_dates.iter().enumerate() {
+    connection.execute(
+      "INSERT INTO worker_blackout_dates (user_id, position, start_date, end_date)
+        VALUES (?1, ?2, ?3, ?4)",
+      params![
+        worker.user_id,
+        position as i64,
+        range.start_date,
+        range.end_date
+      ],
+    )?;
+  }
+
+  Ok(())
+}
+
+fn insert_job(connection: &Connection, job: &JobDto) -> rusqlite::Result<()> {
+  connection.execute(
+    "INSERT INTO jobs (job_id, guid, latitude, longitude, bill_rate, workers_required,
+      driver_license_required, start_date, about, company, minimum_rating, timezone,
+      schedule)
+      VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+    params![
+      job.job_id,
+      job.guid,
+      job.location.latitude,
+      job.location.longitude,
+      job.bill_rate,
+      job.workers_required,
+      job.driver_license_required,
+      job.start_date.to_rfc3339(),
+      job.about,
+      job.company,
+      job.minimum_rating,
+      job.timezone,
+      job
+        .schedule
+        .as_ref()
+        .map(|s| serde_json::to_string(s).unwrap()),
+    ],
+  )?;
+  for (position, certificate) in job.required_certificates.iter().enumerate() {
+    connection.execute(
+      "INSERT INTO job_certificates (job_id, position, certificate) VALUES (?1, ?2, ?3)",
+      params![job.job_id, position as i64, certificate],
+    )?;
+  }
+  let skills = [(true, &job.required_skills), (false, &job.preferred_skills)];
+  for (required, skills) in skills.iter() {
+    for (position, skill) in skills.iter().flat_map(|s| s.iter()).enumerate() {
+      connection.execute(
+        "INSERT INTO job_skills (job_id, required, position, skill) VALUES (?1, ?2, ?3, ?4)",
+        params![job.job_id, required, position as i64, skill],
+      )?;
+    }
+  }
+
+  Ok(())
+}
+
+/// Lookups by id get their own `WHERE <column> = ?1` statement so SQLite can
+/// use the primary key; `None` selects every row.
+fn id_filter(column: &str, id: Option<u32>) -> String {
+  match id {
+    Some(_) => format!("WHERE {} = ?1", column),
+    None => String::new(),
+  }
+}
+
+fn query_children<T, F>(
+  connection: &Connection,
+  sql: &str,
+  id: Option<u32>,
+  map: F,
+) -> rusqlite::Result<HashMap<u32, Vec<T>>>
+where
+  F: Fn(&Row) -> rusqlite::Result<T>,
+{
+  let mut children: HashMap<u32, Vec<T>> = HashMap::new();
+  let mut statement = connection.prepare(sql)?;
+  let mut rows = statement.query(id)?;
+  while let Some(row) = rows.next()? {
+    children.entry(row.get(0)?).or_default().push(map(row)?);
+  }
+
+  Ok(children)
+}
+
+fn query_workers(connection: &Connection, id: Option<u32>) -> rusqlite::Result<Vec<WorkerDto>> {
+  let filter = id_filter("user_id", id);
+  let mut certificates = query_children(
+    connection,
+    &format!(
+      "SELECT user_id, certificate FROM worker_certificates {} ORDER BY user_id, position",
+      filter
+    ),
+    id,
+    |r| r.get(1),
+  )?;
+  let mut skills = query_children(
+    connection,
+    &format!(
+      "SELECT user_id, skill FROM worker_skills {} ORDER BY user_id, position",
+      filter
+    ),
+    id,
+    |r| r.get(1),
+  )?;
+  let mut availability = query_children(
+    connection,
+    &format!(
+      "SELECT user_id, title, day_index, start_time, end_time FROM worker_availability {}
+        ORDER BY user_id, position",
+      filter
+    ),
+    id,
+    |r| {
+      let title: Option<String> = r.get(1)?;
+      let day_index: Option<u32> = r.get(2)?;
+      let start_time: Option<String> = r.get(3)?;
+      let end_time: Option<String> = r.get(4)?;
+      Ok(title.and_then(|title| {
+        day_index.map(|day_index| DayDto {
+          title,
+          day_index,
+          start_time,
+          end_time,
+        })
+      }))
+    },
+  )?;
+  let mut blackout_dates = query_children(
+    connection,
+    &format!(
+      "SELECT user_id, start_date, end_date FROM worker_blackout_dates {}
+        ORDER BY user_id, position",
+      filter
+    ),
+    id,
+    |r| {
+      Ok(DateRangeDto {
+        start_date: r.get(1)?,
+        end_date: r.get(2)?,
+      })
+    },
+  )?;
+
+  let mut statement = connection.prepare(&format!(
+    "SELECT user_id, guid, is_active, phone, email, first_name, last_name, age, rating,
+      search_latitude, search_longitude, max_job_distance, distance_unit, transportation,
+      has_drivers_license, max_commute_minutes FROM workers {} ORDER BY user_id",
+    filter
+  ))?;
+  let workers = statement
+    .query_map(id, |r| {
+      let user_id: u32 = r.get(0)?;
+      Ok(WorkerDto {
+        guid: r.get(1)?,
+        user_id,
+        active: r.get(2)?,
+        phone: r.get(3)?,
+        email: r.get(4)?,
+        name: NameDto {
+          first: r.get(5)?,
+          last: r.get(6)?,
+        },
+        age: r.get(7)?,
+        rating: r.get(8)?,
+        certificates: certificates.remove(&user_id).unwrap_or_default(),
+        skills: skills.remove(&user_id).unwrap_or_default(),
+        job_search_address: GeographicAreaDto {
+          latitude: r.get(9)?,
+          longitude: r.get(10)?,
+          max_job_distance: r.get(11)?,
+          unit: r.get(12)?,
+        },
+        transportation: r.get(13)?,
+        has_drivers_license: r.get(14)?,
+        availability: availability.remove(&user_id).unwrap_or_default(),
+        max_commute_minutes: r.get(15)?,
+        blackout_dates: blackout_dates.remove(&user_id).unwrap_or_default(),
+      })
+    })?
+    .collect::<rusqlite::Result<Vec<WorkerDto>>>()?;
+
+  Ok(workers)
+}
+
+fn skill_list(skills: Vec<(bool, String)>) -> Option<Vec<String>> {
+  if skills.is_empty() {
+    None
+  } else {
+    Some(skills.into_iter().map(|s| s.1).collect())
+  }
+}
+
+fn query_jobs(connection: &Connection, id: Option<u32>) -> rusqlite::Result<Vec<JobDto>> {
+  let filter = id_filter("job_id", id);
+  let mut certificates = query_children(
+    connection,
+    &format!(
+      "SELECT job_id, certificate FROM job_certificates {} ORDER BY job_id, position",
+      filter
+    ),
+    id,
+    |r| r.get(1),
+  )?;
+  let mut skills: HashMap<u32, Vec<(bool, String)>> = query_children(
+    connection,
+    &format!(
+      "SELECT job_id, required, skill FROM job_skills {}
+        ORDER BY job_id, required DESC, position",
+      filter
+    ),
+    id,
+    |r| Ok((r.get(1)?, r.get(2)?)),
+  )?;
+
+  let mut statement = connection.prepare(&format!(
+    "SELECT job_id, guid, latitude, longitude, bill_rate, workers_required,
+      driver_license_required, start_date, about, company, minimum_rating, timezone,
+      schedule
+      FROM jobs {} ORDER BY job_id",
+    filter
+  ))?;
+  let jobs = statement
+    .query_map(id, |r| {
+      let job_id: u32 = r.get(0)?;
+      let start_date: String = r.get(7)?;
+      let schedule: Option<String> = r.get(12)?;
+      let (required_skills, preferred_skills): (Vec<_>, Vec<_>) = skills
+        .remove(&job_id)
+        .unwrap_or_default()
+        .into_iter()
+        .partition(|s| s.0);
+      Ok(JobDto {
+        job_id,
+        guid: r.get(1)?,
+        location: GeographicLocationDto {
+          latitude: r.get(2)?,
+          longitude: r.get(3)?,
+        },
+        bill_rate: r.get(4)?,
+        workers_required: r.get(5)?,
+        driver_license_required: r.get(6)?,
+        required_certificates: certificates.remove(&job_id).unwrap_or_default(),
+        start_date: DateTime::parse_from_rfc3339(&start_date)
+          .map_err(|e| rusqlite::Error::FromSqlConversionFailure(7, Type::Text, Box::new(e)))?,
+        about: r.get(8)?,
+        company: r.get(9)?,
+        required_skills: skill_list(required_skills),
+        preferred_skills: skill_list(preferred_skills),
+        minimum_rating: r.get(10)?,
+        timezone: r.get(11)?,
+        schedule: schedule
+          .map(|s| serde_json::from_str(&s))
+          .transpose()
+          .map_err(|e| rusqlite::Error::FromSqlConversionFailure(12, Type::Text, Box::new(e)))?,
+      })
+    })?
+    .collect::<rusqlite::Result<Vec<JobDto>>>()?;
+
+  Ok(jobs)
+}
+
+#[async_trait]
+impl RestRepository for SqliteRepository {
+  async fn find_all_workers(&self) -> Result<Vec<Worker>, Rejection> {
+    let parsed: ParsedRecords<Worker> =
+      parse(self.with_connection(|c| query_workers(c, None)).await?);
+    self
+      .data_quality
+      .record_dataset(Worker::dataset_name(), parsed.get_report());
+    Ok(parsed.index.to_vec())
+  }
+
+  async fn find_worker_by_id(&self, worker_id: u32) -> Result<Option<Worker>, Rejection> {
+    let parsed: ParsedRecords<Worker> = parse(
+      self
+        .with_connection(move |c| query_workers(c, Some(worker_id)))
+        .await?,
+    );
+    let worker = parsed.index.get(worker_id).cloned();
+    if worker.is_none() {
+      log::warn!("Could not find worker {}", worker_id);
+    }
+
+    Ok(worker)
+  }
+
+  async fn find_all_jobs(&self) -> Result<Vec<Job>, Rejection> {
+    let parsed: ParsedRecords<Job> = parse(self.with_connection(|c| query_jobs(c, None)).await?);
+    self
+      .data_quality
+      .record_dataset(Job::dataset_name(), parsed.get_report());
+    Ok(parsed.index.to_vec())
+  }
+
+  async fn find_job_by_id(&self, job_id: u32) -> Result<Option<Job>, Rejection> {
+    let parsed: ParsedRecords<Job> = parse(
+      self
+        .with_connection(move |c| query_jobs(c, Some(job_id)))
+        .await?,
+    );
+    let job = parsed.index.get(job_id).cloned();
+    if job.is_none() {
+      log::warn!("Could not find Job {}", job_id);
+    }
+
+    Ok(job)
+  }
+}
+
+#[async_trait]
+impl RecordStore for SqliteRepository {
+  async fn upsert_worker(&self, worker: Worker) -> Result<(), Rejection> {
+    let worker = WorkerDto::from(&worker);
+    self
+      .with_connection(move |c| {
+        let tx = c.transaction()?;
+        tx.execute(
+          "DELETE FROM workers WHERE user_id = ?1",
+          params![worker.user_id],
+        )?;
+        insert_worker(&tx, &worker)?;
+        tx.commit()
+      })
+      .await
+  }
+
+  async fn delete_worker(&self, worker_id: u32) -> Result<bool, Rejection> {
+    self
+      .with_connection(move |c| {
+        c.execute("DELETE FROM workers WHERE user_id = ?1", params![worker_id])
+          .map(|n| n > 0)
+      })
+      .await
+  }
+
+  async fn upsert_job(&self, job: Job) -> Result<(), Rejection> {
+    let job = JobDto::from(&job);
+    self
+      .with_connection(move |c| {
+        let tx = c.transaction()?;
+        tx.execute("DELETE FROM jobs WHERE job_id = ?1", params![job.job_id])?;
+        insert_job(&tx, &job)?;
+        tx.commit()
+      })
+      .await
+  }
+
+  async fn delete_job(&self, job_id: u32) -> Result<bool, Rejection> {
+    self
+      .with_connection(move |c| {
+        c.execute("DELETE FROM jobs WHERE job_id = ?1", params![job_id])
+          .map(|n| n > 0)
+      })
+      .await
+  }
+}
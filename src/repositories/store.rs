@@ -0,0 +1,76 @@
+use super::index::{IdIndex, Identifiable};
+use crate::domain::job::Job;
+use crate::domain::worker::Worker;
+use async_trait::async_trait;
+use std::sync::Mutex;
+use warp::reject::Rejection;
+
+#[async_trait]
+pub trait RecordStore {
+  async fn upsert_worker(&self, worker: Worker) -> Result<(), Rejection>;
+  async fn delete_worker(&self, worker_id: u32) -> Result<bool, Rejection>;
+  async fn upsert_job(&self, job: Job) -> Result<(), Rejection>;
+  async fn delete_job(&self, job_id: u32) -> Result<bool, Rejection>;
+}
+
+/// Records pushed into a store whose source cannot be written to. A push is
+/// re-applied on top of snapshots whose fetch started before it, so an
+/// in-flight refresh does not undo it, and dropped once a snapshot fetched
+/// after it has been stored, so later upstream changes win.
+pub struct PushedRecords<T> {
+  changes: Mutex<PushedChanges<T>>,
+}
+
+struct PushedChanges<T> {
+  next_sequence: u64,
+  entries: Vec<(u64, u32, Option<T>)>,
+}
+
+impl<T: Identifiable + Clone> PushedRecords<T> {
+  pub fn new() -> PushedRecords<T> {
+    PushedRecords {
+      changes: Mutex::new(PushedChanges {
+        next_sequence: 0,
+        entries: Vec::new(),
+      }),
+    }
+  }
+
+  /// Marks the start of a fetch; pass the result to `apply` once it completes.
+  pub fn checkpoint(&self) -> u64 {
+    self.changes.lock().unwrap().next_sequence
+  }
+
+  fn record(&self, id: u32, change: Option<T>) {
+    let mut changes = self.changes.lock().unwrap();
+    let sequence = changes.next_sequence;
+    changes.next_sequence += 1;
+    changes.entries.retain(|(_, i, _)| *i != id);
+    changes.entries.push((sequence, id, change));
+  }
+
+  pub fn upsert(&self, index: &mut IdIndex<T>, entry: T) {
+    self.record(entry.get_id(), Some(entry.clone()));
+    index.upsert(entry);
+  }
+
+  pub fn remove(&self, index: &mut IdIndex<T>, id: u32) -> bool {
+    self.record(id, None);
+    index.remove(id).is_some()
+  }
+
+  pub fn apply(&self, index: &mut IdIndex<T>, checkpoint: u64) {
+    let mut changes = self.changes.lock().unwrap();
+    changes
+      .entries
+      .retain(|(sequence, _, _)| *sequence >= checkpoint);
+    for (_, id, change) in changes.entries.iter() {
+      match change {
+        Some(entry) => index.upsert(entry.clone()),
+        None => {
+          index.remove(*id);
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,168 @@
+use crate::fixtures::{job, worker, StubRepository};
+use crate::repositories::caching::{CacheStatusService, CachingRestRepository};
+use crate::repositories::rest::RestRepository;
+use crate::repositories::store::RecordStore;
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+
+fn stub() -> Arc<StubRepository> {
+  Arc::new(StubRepository::new(
+    vec![worker(1, json!({})), worker(2, json!({}))],
+    vec![job(7, json!({}))],
+  ))
+}
+
+fn cache(stub: &Arc<StubRepository>, ttl_ms: u64) -> Arc<CachingRestRepository> {
+  Arc::new(CachingRestRepository::new(
+    stub.clone(),
+    Duration::from_millis(ttl_ms),
+    Duration::from_secs(60),
+    Duration::from_millis(20),
+  ))
+}
+
+#[tokio::test]
+async fn test_serves_cached_data_until_ttl_expires() {
+  tokio::time::pause();
+  let stub = stub();
+  let cache = cache(&stub, 100);
+  assert_eq!(cache.find_all_workers().await.unwrap().len(), 2);
+  assert!(cache.find_worker_by_id(2).await.unwrap().is_some());
+  assert_eq!(stub.fetch_count(), 1);
+
+  tokio::time::advance(Duration::from_millis(100)).await;
+  assert_eq!(cache.find_all_workers().await.unwrap().len(), 2);
+  assert_eq!(stub.fetch_count(), 1);
+
+  tokio::time::advance(Duration::from_millis(1)).await;
+  assert_eq!(cache.find_all_workers().await.unwrap().len(), 2);
+  assert_eq!(stub.fetch_count(), 2);
+}
+
+#[tokio::test]
+async fn test_counts_hits_and_misses_per_dataset() {
+  let stub = stub();
+  let cache = cache(&stub, 60_000);
+  cache.find_all_workers().await.unwrap();
+  cache.find_all_workers().await.unwrap();
+  cache.find_worker_by_id(1).await.unwrap();
+  cache.find_all_jobs().await.unwrap();
+
+  let status = cache.get_cache_status();
+  let workers = status.workers.unwrap();
+  assert_eq!((workers.hits, workers.misses), (2, 1));
+  assert_eq!(workers.size, 2);
+  let jobs = status.jobs.unwrap();
+  assert_eq!((jobs.hits, jobs.misses), (0, 1));
+}
+
+#[tokio::test]
+async fn test_refreshes_in_the_background() {
+  tokio::time::pause();
+  let stub = stub();
+  let cache = cache(&stub, 60_000);
+  cache.start_refresh();
+  tokio::time::delay_for(Duration::from_millis(50)).await;
+  assert_eq!(stub.fetch_count(), 6);
+
+  let fetches = stub.fetch_count();
+  cache.find_all_workers().await.unwrap();
+  cache.find_all_jobs().await.unwrap();
+  assert_eq!(stub.fetch_count(), fetches);
+  assert!(cache
+    .get_cache_status()
+    .workers
+    .unwrap()
+    .last_refresh
+    .is_some());
+}
+
+#[tokio::test]
+async fn test_flags_stale_data_per_request() {
+  let stub = stub();
+  let cache = cache(&stub, 0);
+  assert!(!cache.fetch_all_workers().await.unwrap().stale);
+
+  stub.set_failing(true);
+  let workers = cache.fetch_all_workers().await.unwrap();
+  assert!(workers.stale);
+  assert_eq!(workers.value.len(), 2);
+  assert!(cache.get_cache_status().workers.unwrap().stale);
+
+  stub.set_failing(false);
+  assert!(!cache.fetch_all_workers().await.unwrap().stale);
+  assert!(!cache.fetch_job_by_id(7).await.unwrap().stale);
+}
+
+#[tokio::test]
+async fn test_fails_once_data_exceeds_max_staleness() {
+  tokio::time::pause();
+  let stub = stub();
+  let cache = CachingRestRepository::new(
+    stub.clone(),
+    Duration::from_millis(0),
+    Duration::from_millis(30),
+    Duration::from_millis(20),
+  );
+  cache.find_all_jobs().await.unwrap();
+  stub.set_failing(true);
+  tokio::time::advance(Duration::from_millis(1)).await;
+  assert!(cache.fetch_all_jobs().await.unwrap().stale);
+
+  tokio::time::advance(Duration::from_millis(30)).await;
+  assert!(cache.fetch_all_jobs().await.is_err());
+}
+
+#[tokio::test]
+async fn test_serves_stale_data_without_fetching_while_upstream_unavailable() {
+  let stub = stub();
+  let cache = cache(&stub, 0);
+  cache.find_all_workers().await.unwrap();
+  stub.set_available(false);
+
+  let worker = cache.fetch_worker_by_id(2).await.unwrap();
+  assert!(worker.stale);
+  assert!(worker.value.is_some());
+  assert_eq!(stub.fetch_count(), 1);
+}
+
+#[tokio::test]
+async fn test_keeps_pushed_records_until_the_next_fetch() {
+  let stub = stub();
+  let cache = cache(&stub, 60_000);
+  cache.upsert_worker(worker(3, json!({}))).await.unwrap();
+  assert!(cache.delete_worker(1).await.unwrap());
+  cache
+    .upsert_job(job(7, json!({ "company": "Pushed" })))
+    .await
+    .unwrap();
+
+  let workers = cache.find_all_workers().await.unwrap();
+  assert_eq!(
+    workers.iter().map(|w| w.user_id).collect::<Vec<u32>>(),
+    vec![2, 3]
+  );
+  let job = cache.find_job_by_id(7).await.unwrap().unwrap();
+  assert_eq!(job.company, "Pushed");
+  assert_eq!(stub.fetch_count(), 2);
+}
+
+#[tokio::test]
+async fn test_upstream_changes_replace_earlier_pushes() {
+  let stub = stub();
+  let cache = cache(&stub, 0);
+  cache
+    .upsert_worker(worker(2, json!({ "rating": 1 })))
+    .await
+    .unwrap();
+  stub.set_workers(vec![
+    worker(1, json!({})),
+    worker(2, json!({ "rating": 5 })),
+  ]);
+  let worker = cache.find_worker_by_id(2).await.unwrap().unwrap();
+  assert_eq!(worker.rating, 5);
+
+  assert!(cache.delete_worker(1).await.unwrap());
+  assert!(cache.find_worker_by_id(1).await.unwrap().is_some());
+}
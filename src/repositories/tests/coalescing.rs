@@ -0,0 +1,73 @@
+use crate::fixtures::{job, worker, StubRepository};
+use crate::repositories::coalescing::CoalescingRestRepository;
+use crate::repositories::rest::RestRepository;
+use serde_json::json;
+use std::sync::Arc;
+
+fn stub() -> Arc<StubRepository> {
+  Arc::new(StubRepository::new(
+    vec![worker(1, json!({})), worker(2, json!({}))],
+    vec![job(7, json!({}))],
+  ))
+}
+
+#[tokio::test]
+async fn test_concurrent_requests_share_one_fetch() {
+  let stub = stub();
+  let repository = CoalescingRestRepository::new(stub.clone());
+
+  let (first, second, third) = tokio::join!(
+    repository.find_all_workers(),
+    repository.find_all_workers(),
+    repository.find_all_workers(),
+  );
+
+  assert_eq!(first.unwrap().len(), 2);
+  assert_eq!(second.unwrap().len(), 2);
+  assert_eq!(third.unwrap().len(), 2);
+  assert_eq!(stub.fetch_count(), 1);
+}
+
+#[tokio::test]
+async fn test_sequential_requests_fetch_again() {
+  let stub = stub();
+  let repository = CoalescingRestRepository::new(stub.clone());
+
+  repository.find_all_jobs().await.unwrap();
+  repository.find_all_jobs().await.unwrap();
+
+  assert_eq!(stub.fetch_count(), 2);
+}
+
+#[tokio::test]
+async fn test_failed_fetch_is_not_cached() {
+  let stub = stub();
+  let repository = CoalescingRestRepository::new(stub.clone());
+  stub.set_failing(true);
+
+  let (first, second) = tokio::join!(repository.find_all_jobs(), repository.find_all_jobs());
+  assert!(first.is_err());
+  assert!(second.is_err());
+  assert_eq!(stub.fetch_count(), 1);
+
+  stub.set_failing(false);
+  assert_eq!(repository.find_all_jobs().await.unwrap().len(), 1);
+  assert_eq!(stub.fetch_count(), 2);
+}
+
+#[tokio::test]
+async fn test_lookups_by_id_join_the_in_flight_fetch() {
+  let stub = stub();
+  let repository = CoalescingRestRepository::new(stub.clone());
+
+  let (all, first, missing) = tokio::join!(
+    repository.find_all_workers(),
+    repository.find_worker_by_id(1),
+    repository.find_worker_by_id(3),
+  );
+
+  assert_eq!(all.unwrap().len(), 2);
+  assert!(first.unwrap().is_some());
+  assert!(missing.unwrap().is_none());
+  assert_eq!(stub.fetch_count(), 1);
+}
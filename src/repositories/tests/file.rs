@@ -0,0 +1,146 @@
+use crate::fixtures::{job_dto, worker, worker_dto};
+use crate::repositories::data_quality::{DataQualityService, DataQualityServiceImpl};
+use crate::repositories::file::FileRepository;
+use crate::repositories::rest::RestRepository;
+use crate::repositories::store::RecordStore;
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+
+fn directory(name: &str) -> PathBuf {
+  let directory =
+    std::env::temp_dir().join(format!("jobmatching-file-{}-{}", name, std::process::id()));
+  let _ = std::fs::remove_dir_all(&directory);
+  std::fs::create_dir_all(&directory).unwrap();
+  directory
+}
+
+fn write_workers(directory: &Path, ids: &[u32]) {
+  let workers: Vec<_> = ids.iter().map(|id| worker_dto(*id, json!({}))).collect();
+  std::fs::write(
+    directory.join("workers.json"),
+    serde_json::to_string(&workers).unwrap(),
+  )
+  .unwrap();
+}
+
+fn write_jobs_ndjson(directory: &Path, lines: &[String]) {
+  std::fs::write(directory.join("jobs.ndjson"), lines.join("\n")).unwrap();
+}
+
+fn job_line(job_id: u32) -> String {
+  serde_json::to_string(&job_dto(job_id, json!({}))).unwrap()
+}
+
+fn repository(directory: &Path) -> (Arc<FileRepository>, Arc<DataQualityServiceImpl>) {
+  let data_quality = Arc::new(DataQualityServiceImpl::new());
+  let repository = Arc::new(FileRepository::new(
+    directory.to_string_lossy().to_string(),
+    data_quality.clone(),
+  ));
+  (repository, data_quality)
+}
+
+#[tokio::test]
+async fn test_loads_json_and_ndjson_files() {
+  let directory = directory("formats");
+  write_workers(&directory, &[1, 2]);
+  write_jobs_ndjson(&directory, &[job_line(7), String::new(), job_line(8)]);
+  let (repository, _) = repository(&directory);
+  repository.load().await.unwrap();
+
+  assert_eq!(repository.find_all_workers().await.unwrap().len(), 2);
+  let jobs = repository.find_all_jobs().await.unwrap();
+  assert_eq!(
+    jobs.iter().map(|j| j.job_id).collect::<Vec<u32>>(),
+    vec![7, 8]
+  );
+}
+
+#[tokio::test]
+async fn test_reports_malformed_ndjson_lines() {
+  let directory = directory("bad-lines");
+  write_workers(&directory, &[1]);
+  write_jobs_ndjson(
+    &directory,
+    &[job_line(7), String::from("{\"jobId\": "), job_line(8)],
+  );
+  let (repository, data_quality) = repository(&directory);
+  repository.load().await.unwrap();
+
+  assert_eq!(repository.find_all_jobs().await.unwrap().len(), 2);
+  let report = data_quality.get_report().remove("jobs").unwrap();
+  assert_eq!((report.records, report.skipped), (3, 1));
+  assert_eq!(report.issues[0].kind, "parse_error");
+  assert!(report.issues[0].message.starts_with("Line 2: "));
+}
+
+#[tokio::test]
+async fn test_describes_missing_and_malformed_files() {
+  let directory = directory("errors");
+  let (repository, _) = repository(&directory);
+  let error = repository.load().await.err().unwrap();
+  assert!(error.starts_with("Could not read "));
+  assert!(error.contains("workers.json"));
+
+  std::fs::write(directory.join("workers.json"), "[{").unwrap();
+  let error = repository.load().await.err().unwrap();
+  assert!(error.starts_with("Could not parse "));
+}
+
+#[tokio::test]
+async fn test_reloads_files_that_changed() {
+  let directory = directory("reload");
+  write_workers(&directory, &[1]);
+  write_jobs_ndjson(&directory, &[job_line(7)]);
+  let (repository, _) = repository(&directory);
+  repository.load().await.unwrap();
+  repository.start_watching(Duration::from_millis(20));
+
+  write_workers(&directory, &[1, 2, 3]);
+  std::fs::File::open(directory.join("workers.json"))
+    .unwrap()
+    .set_modified(SystemTime::now() + Duration::from_secs(1))
+    .unwrap();
+
+  let deadline = Instant::now() + Duration::from_secs(5);
+  while repository.find_all_workers().await.unwrap().len() != 3 {
+    assert!(Instant::now() < deadline, "workers.json was not reloaded");
+    tokio::time::delay_for(Duration::from_millis(10)).await;
+  }
+  assert_eq!(repository.find_all_jobs().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_keeps_pushed_records_until_the_file_is_reloaded() {
+  let directory = directory("pushed");
+  write_workers(&directory, &[1, 2]);
+  write_jobs_ndjson(&directory, &[job_line(7)]);
+  let (repository, _) = repository(&directory);
+  repository.load().await.unwrap();
+  repository
+    .upsert_worker(worker(2, json!({ "rating": 1 })))
+    .await
+    .unwrap();
+  assert!(repository.delete_worker(1).await.unwrap());
+  let workers = repository.find_all_workers().await.unwrap();
+  assert_eq!(
+    workers
+      .iter()
+      .map(|w| (w.user_id, w.rating))
+      .collect::<Vec<_>>(),
+    vec![(2, 1)]
+  );
+
+  write_workers(&directory, &[1, 2, 3]);
+  repository.load().await.unwrap();
+  let workers = repository.find_all_workers().await.unwrap();
+  assert_eq!(
+    workers
+      .iter()
+      .map(|w| (w.user_id, w.rating))
+      .collect::<Vec<_>>(),
+    vec![(1, 4), (2, 4), (3, 4)]
+  );
+}
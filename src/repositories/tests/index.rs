@@ -0,0 +1,95 @@
+use crate::repositories::index::{IdIndex, Identifiable};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Entry {
+  id: u32,
+  guid: &'static str,
+}
+
+impl Identifiable for Entry {
+  fn entity_name() -> &'static str {
+    "Entry"
+  }
+
+  fn dataset_name() -> &'static str {
+    "entries"
+  }
+
+  fn id_field() -> &'static str {
+    "id"
+  }
+
+  fn get_id(&self) -> u32 {
+    self.id
+  }
+
+  fn get_guid(&self) -> &str {
+    self.guid
+  }
+}
+
+fn entry(id: u32, guid: &'static str) -> Entry {
+  Entry { id, guid }
+}
+
+#[test]
+fn test_lookup() {
+  let index = IdIndex::new(vec![entry(3, "c"), entry(1, "a"), entry(2, "b")]);
+
+  assert_eq!(index.get(1), Some(&entry(1, "a")));
+  assert_eq!(index.get(3), Some(&entry(3, "c")));
+  assert_eq!(index.get(4), None);
+  assert_eq!(index.len(), 3);
+}
+
+#[test]
+fn test_preserves_order() {
+  let index = IdIndex::new(vec![entry(3, "c"), entry(1, "a"), entry(2, "b")]);
+
+  assert_eq!(
+    index.to_vec(),
+    vec![entry(3, "c"), entry(1, "a"), entry(2, "b")]
+  );
+}
+
+#[test]
+fn test_duplicate_ids() {
+  let index = IdIndex::new(vec![entry(1, "a"), entry(2, "b"), entry(1, "c")]);
+
+  assert_eq!(index.get(1), Some(&entry(1, "a")));
+  assert_eq!(index.len(), 2);
+  assert_eq!(index.get_duplicate_ids(), &[1]);
+  assert!(index.get_duplicate_guids().is_empty());
+}
+
+#[test]
+fn test_duplicate_guids() {
+  let index = IdIndex::new(vec![entry(1, "a"), entry(2, "a")]);
+
+  assert_eq!(index.get(2), Some(&entry(2, "a")));
+  assert_eq!(index.len(), 2);
+  assert!(index.get_duplicate_ids().is_empty());
+  assert_eq!(index.get_duplicate_guids(), &[String::from("a")]);
+}
+
+#[test]
+fn test_upsert() {
+  let mut index = IdIndex::new(vec![entry(3, "c"), entry(1, "a")]);
+  index.upsert(entry(1, "z"));
+  index.upsert(entry(2, "b"));
+
+  assert_eq!(
+    index.to_vec(),
+    vec![entry(3, "c"), entry(1, "z"), entry(2, "b")]
+  );
+}
+
+#[test]
+fn test_remove() {
+  let mut index = IdIndex::new(vec![entry(3, "c"), entry(1, "a"), entry(2, "b")]);
+
+  assert_eq!(index.remove(1), Some(entry(1, "a")));
+  assert_eq!(index.remove(1), None);
+  assert_eq!(index.to_vec(), vec![entry(3, "c"), entry(2, "b")]);
+  assert_eq!(index.len(), 2);
+}
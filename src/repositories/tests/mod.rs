@@ -0,0 +1,9 @@
+pub mod caching;
+pub mod coalescing;
+pub mod file;
+pub mod index;
+pub mod parsing;
+pub mod resilience;
+pub mod rest;
+pub mod sqlite;
+pub mod store;
@@ -0,0 +1,134 @@
+use crate::domain::availability::{Availability, DayAvailability};
+use crate::domain::job::Job;
+use crate::domain::weekday::Weekday;
+use crate::domain::worker::Worker;
+use crate::fixtures::{job_dto, worker_dto};
+use crate::repositories::parsing::ParsedRecords;
+use serde_json::{json, Value};
+
+fn job(id: u32, latitude: &str, bill_rate: &str) -> Value {
+  serde_json::to_value(job_dto(
+    id,
+    json!({
+      "location": { "latitude": latitude, "longitude": "13.9" },
+      "billRate": bill_rate,
+    }),
+  ))
+  .unwrap()
+}
+
+#[test]
+fn test_skips_records_that_cannot_be_parsed() {
+  let mut malformed = job(2, "49.7", "$10.00");
+  malformed["startDate"] = json!("not a date");
+  let parsed: ParsedRecords<Job> = ParsedRecords::parse(vec![
+    Ok(job(1, "49.7", "$10.00")),
+    Ok(malformed),
+    Err(String::from("Line 3: EOF while parsing")),
+  ]);
+
+  let report = parsed.get_report();
+  assert_eq!(parsed.index.len(), 1);
+  assert_eq!(report.records, 3);
+  assert_eq!(report.skipped, 2);
+  assert_eq!(report.issues[0].id, Some(2));
+  assert_eq!(report.issues[0].kind, "parse_error");
+  assert_eq!(report.issues[1].id, None);
+}
+
+#[test]
+fn test_keeps_records_with_invalid_location_or_bill_rate() {
+  let parsed: ParsedRecords<Job> = ParsedRecords::parse(vec![
+    Ok(job(1, "north", "$10.00")),
+    Ok(job(2, "49.7", "ten dollars")),
+  ]);
+
+  let report = parsed.get_report();
+  assert_eq!(parsed.index.len(), 2);
+  assert!(parsed.index.get(1).unwrap().location.is_none());
+  assert!(parsed.index.get(2).unwrap().bill_rate.is_none());
+  assert_eq!(report.skipped, 0);
+  assert_eq!(report.issues.len(), 2);
+  assert!(report
+    .issues
+    .iter()
+    .all(|i| i.kind == "invalid_field" && !i.skipped));
+  assert!(report.issues[0]
+    .message
+    .contains("location: Could not parse latitude"));
+  assert!(report.issues[1].message.contains("billRate"));
+}
+
+#[test]
+fn test_keeps_workers_with_an_unknown_distance_unit() {
+  let worker = worker_dto(
+    3,
+    json!({ "jobSearchAddress": { "latitude": "49.78", "longitude": "13.9", "maxJobDistance": 30, "unit": "furlongs" } }),
+  );
+  let parsed: ParsedRecords<Worker> =
+    ParsedRecords::parse(vec![Ok(serde_json::to_value(worker).unwrap())]);
+
+  let report = parsed.get_report();
+  assert!(parsed
+    .index
+    .get(3)
+    .unwrap()
+    .job_search_address
+    .unit
+    .is_none());
+  assert_eq!(report.skipped, 0);
+  assert_eq!(report.issues.len(), 1);
+  assert_eq!(report.issues[0].kind, "invalid_field");
+  assert!(report.issues[0].message.contains("jobSearchAddress.unit"));
+}
+
+#[test]
+fn test_skips_records_with_invalid_fields() {
+  let mut job = job(1, "49.7", "$10.00");
+  job["timezone"] = json!("Mars/Olympus");
+  let parsed: ParsedRecords<Job> = ParsedRecords::parse(vec![Ok(job)]);
+
+  let report = parsed.get_report();
+  assert_eq!(parsed.index.len(), 0);
+  assert_eq!(report.skipped, 1);
+  assert_eq!(report.issues[0].kind, "invalid_field");
+  assert!(report.issues[0].message.contains("timezone"));
+}
+
+#[test]
+fn test_drops_only_invalid_availability_entries() {
+  let mut worker = serde_json::to_value(worker_dto(3, json!({}))).unwrap();
+  worker["availability"] = json!([
+    { "title": "Monday", "dayIndex": 1 },
+    { "title": "Someday", "dayIndex": 9 },
+    { "title": "Tuesday", "dayIndex": 2, "startTime": "late", "endTime": "17:00" }
+  ]);
+  let parsed: ParsedRecords<Worker> = ParsedRecords::parse(vec![Ok(worker)]);
+
+  let report = parsed.get_report();
+  let worker = parsed.index.get(3).unwrap();
+  assert_eq!(
+    worker.availability,
+    Availability::new(vec![DayAvailability {
+      day: Weekday::Monday,
+      window: None,
+    }])
+  );
+  assert_eq!(report.skipped, 0);
+  assert_eq!(report.issues.len(), 2);
+  assert!(report.issues[0].message.contains("dayIndex 9"));
+  assert!(report.issues[1].message.contains("startTime"));
+}
+
+#[test]
+fn test_reports_duplicate_ids_as_skipped() {
+  let parsed: ParsedRecords<Job> = ParsedRecords::parse(vec![
+    Ok(job(1, "49.7", "$10.00")),
+    Ok(job(1, "49.8", "$11.00")),
+  ]);
+
+  let report = parsed.get_report();
+  assert_eq!(parsed.index.len(), 1);
+  assert_eq!(report.skipped, 1);
+  assert_eq!(report.issues[0].kind, "duplicate_id");
+}
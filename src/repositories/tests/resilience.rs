@@ -0,0 +1,217 @@
+use crate::repositories::resilience::{CircuitBreaker, RequestError, ResilientClient, RetryPolicy};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use warp::http::StatusCode;
+use warp::Filter;
+
+fn start_stub(
+  failures: usize,
+  failure_status: StatusCode,
+  delay: Duration,
+) -> (SocketAddr, Arc<AtomicUsize>) {
+  let hits = Arc::new(AtomicUsize::new(0));
+  let counter = hits.clone();
+  let route = warp::path("items").and_then(move || {
+    let hit = counter.fetch_add(1, Ordering::SeqCst);
+    async move {
+      tokio::time::delay_for(delay).await;
+      let status = if hit < failures {
+        failure_status
+      } else {
+        StatusCode::OK
+      };
+      Ok::<_, warp::Rejection>(warp::reply::with_status(
+        warp::reply::json(&vec![1, 2, 3]),
+        status,
+      ))
+    }
+  });
+  let (addr, server) = warp::serve(route).bind_ephemeral(([127, 0, 0, 1], 0));
+  tokio::spawn(server);
+
+  (addr, hits)
+}
+
+fn build_client(max_retries: u32, failure_threshold: u32, reset: Duration) -> ResilientClient {
+  ResilientClient::new(
+    Duration::from_millis(200),
+    4,
+    RetryPolicy::new(
+      max_retries,
+      Duration::from_millis(1),
+      Duration::from_millis(5),
+    ),
+    CircuitBreaker::new(failure_threshold, reset),
+  )
+}
+
+fn url(addr: SocketAddr) -> String {
+  format!("http://{}/items", addr)
+}
+
+async fn wait_for_reset(client: &ResilientClient) {
+  let deadline = Instant::now() + Duration::from_secs(5);
+  while client.is_circuit_open() {
+    assert!(Instant::now() < deadline, "circuit breaker did not reset");
+    tokio::time::delay_for(Duration::from_millis(5)).await;
+  }
+}
+
+#[tokio::test]
+async fn test_retries_until_success() {
+  let (addr, hits) = start_stub(
+    2,
+    StatusCode::INTERNAL_SERVER_ERROR,
+    Duration::from_millis(0),
+  );
+  let client = build_client(3, 10, Duration::from_secs(60));
+
+  let result: Result<Vec<u32>, RequestError> = client.get_json(&url(addr)).await;
+
+  assert_eq!(result.unwrap(), vec![1, 2, 3]);
+  assert_eq!(hits.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_gives_up_after_max_retries() {
+  let (addr, hits) = start_stub(
+    10,
+    StatusCode::SERVICE_UNAVAILABLE,
+    Duration::from_millis(0),
+  );
+  let client = build_client(2, 10, Duration::from_secs(60));
+
+  let result: Result<Vec<u32>, RequestError> = client.get_json(&url(addr)).await;
+
+  assert!(matches!(
+    result,
+    Err(RequestError::Status(StatusCode::SERVICE_UNAVAILABLE))
+  ));
+  assert_eq!(hits.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_client_errors_not_retried() {
+  let (addr, hits) = start_stub(10, StatusCode::NOT_FOUND, Duration::from_millis(0));
+  let client = build_client(3, 10, Duration::from_secs(60));
+
+  let result: Result<Vec<u32>, RequestError> = client.get_json(&url(addr)).await;
+
+  assert!(matches!(
+    result,
+    Err(RequestError::Status(StatusCode::NOT_FOUND))
+  ));
+  assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_timeout() {
+  let (addr, hits) = start_stub(0, StatusCode::OK, Duration::from_secs(2));
+  let client = build_client(0, 10, Duration::from_secs(60));
+
+  let result: Result<Vec<u32>, RequestError> = client.get_json(&url(addr)).await;
+
+  assert!(matches!(result, Err(RequestError::Transport(_))));
+  assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_circuit_opens() {
+  let (addr, hits) = start_stub(
+    10,
+    StatusCode::INTERNAL_SERVER_ERROR,
+    Duration::from_millis(0),
+  );
+  let client = build_client(0, 2, Duration::from_secs(60));
+
+  for _ in 0..2 {
+    let result: Result<Vec<u32>, RequestError> = client.get_json(&url(addr)).await;
+    assert!(matches!(result, Err(RequestError::Status(_))));
+  }
+  let result: Result<Vec<u32>, RequestError> = client.get_json(&url(addr)).await;
+
+  assert!(matches!(result, Err(RequestError::CircuitOpen)));
+  assert!(client.is_circuit_open());
+  assert_eq!(hits.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_circuit_half_open() {
+  let (addr, hits) = start_stub(
+    2,
+    StatusCode::INTERNAL_SERVER_ERROR,
+    Duration::from_millis(0),
+  );
+  let client = build_client(0, 2, Duration::from_millis(50));
+
+  for _ in 0..2 {
+    let result: Result<Vec<u32>, RequestError> = client.get_json(&url(addr)).await;
+    assert!(matches!(result, Err(RequestError::Status(_))));
+  }
+  let result: Result<Vec<u32>, RequestError> = client.get_json(&url(addr)).await;
+  assert!(matches!(result, Err(RequestError::CircuitOpen)));
+
+  wait_for_reset(&client).await;
+  let result: Result<Vec<u32>, RequestError> = client.get_json(&url(addr)).await;
+
+  assert_eq!(result.unwrap(), vec![1, 2, 3]);
+  assert_eq!(hits.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_dropped_trial_does_not_keep_circuit_open() {
+  let (failing, _) = start_stub(
+    10,
+    StatusCode::INTERNAL_SERVER_ERROR,
+    Duration::from_millis(0),
+  );
+  let (slow, _) = start_stub(0, StatusCode::OK, Duration::from_millis(150));
+  let (healthy, hits) = start_stub(0, StatusCode::OK, Duration::from_millis(0));
+  let client = build_client(0, 1, Duration::from_millis(50));
+
+  let result: Result<Vec<u32>, RequestError> = client.get_json(&url(failing)).await;
+  assert!(matches!(result, Err(RequestError::Status(_))));
+  wait_for_reset(&client).await;
+  let slow_url = url(slow);
+  let trial = client.get_json::<Vec<u32>>(&slow_url);
+  assert!(tokio::time::timeout(Duration::from_millis(20), trial)
+    .await
+    .is_err());
+
+  wait_for_reset(&client).await;
+  let result: Result<Vec<u32>, RequestError> = client.get_json(&url(healthy)).await;
+
+  assert_eq!(result.unwrap(), vec![1, 2, 3]);
+  assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_client_error_does_not_close_half_open_circuit() {
+  let (failing, hits) = start_stub(
+    10,
+    StatusCode::INTERNAL_SERVER_ERROR,
+    Duration::from_millis(0),
+  );
+  let (missing, _) = start_stub(10, StatusCode::NOT_FOUND, Duration::from_millis(0));
+  let client = build_client(0, 2, Duration::from_millis(50));
+
+  for _ in 0..2 {
+    let result: Result<Vec<u32>, RequestError> = client.get_json(&url(failing)).await;
+    assert!(matches!(result, Err(RequestError::Status(_))));
+  }
+  wait_for_reset(&client).await;
+  let result: Result<Vec<u32>, RequestError> = client.get_json(&url(missing)).await;
+  assert!(matches!(
+    result,
+    Err(RequestError::Status(StatusCode::NOT_FOUND))
+  ));
+
+  let result: Result<Vec<u32>, RequestError> = client.get_json(&url(failing)).await;
+  assert!(matches!(result, Err(RequestError::Status(_))));
+  let result: Result<Vec<u32>, RequestError> = client.get_json(&url(failing)).await;
+
+  assert!(matches!(result, Err(RequestError::CircuitOpen)));
+  assert_eq!(hits.load(Ordering::SeqCst), 3);
+}
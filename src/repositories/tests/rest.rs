@@ -0,0 +1,96 @@
+use crate::fixtures::worker_dto;
+use crate::repositories::coalescing::CoalescingRestRepository;
+use crate::repositories::data_quality::DataQualityServiceImpl;
+use crate::repositories::resilience::{CircuitBreaker, ResilientClient, RetryPolicy};
+use crate::repositories::rest::{RestRepository, RestRepositoryImpl};
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use warp::Filter;
+
+fn serve_workers(requests: Arc<AtomicUsize>) -> String {
+  let route = warp::path!("workers").map(move || {
+    requests.fetch_add(1, Ordering::SeqCst);
+    warp::reply::json(&vec![worker_dto(1, json!({})), worker_dto(2, json!({}))])
+  });
+  let (address, server) = warp::serve(route).bind_ephemeral(([127, 0, 0, 1], 0));
+  tokio::spawn(server);
+  format!("http://{}", address)
+}
+
+fn repository(base_url: String, index_max_age: Duration) -> RestRepositoryImpl {
+  RestRepositoryImpl::new(
+    base_url,
+    ResilientClient::new(
+      Duration::from_secs(5),
+      4,
+      RetryPolicy::new(0, Duration::from_millis(1), Duration::from_millis(1)),
+      CircuitBreaker::new(5, Duration::from_secs(30)),
+    ),
+    index_max_age,
+    Arc::new(DataQualityServiceImpl::new()),
+  )
+}
+
+#[tokio::test]
+async fn test_looks_up_ids_in_the_loaded_index() {
+  let requests = Arc::new(AtomicUsize::new(0));
+  let repository = repository(serve_workers(requests.clone()), Duration::from_secs(60));
+
+  assert!(repository.find_worker_by_id(1).await.unwrap().is_some());
+  assert!(repository.find_worker_by_id(2).await.unwrap().is_some());
+  assert!(repository.find_worker_by_id(3).await.unwrap().is_none());
+  assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+  assert_eq!(repository.find_all_workers().await.unwrap().len(), 2);
+  assert_eq!(requests.load(Ordering::SeqCst), 2);
+  assert!(repository.find_worker_by_id(1).await.unwrap().is_some());
+  assert_eq!(requests.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_reloads_index_once_it_is_too_old() {
+  let requests = Arc::new(AtomicUsize::new(0));
+  let repository = repository(serve_workers(requests.clone()), Duration::from_millis(0));
+
+  assert!(repository.find_worker_by_id(1).await.unwrap().is_some());
+  tokio::time::delay_for(Duration::from_millis(5)).await;
+  assert!(repository.find_worker_by_id(1).await.unwrap().is_some());
+  assert_eq!(requests.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_concurrent_lookups_share_one_reload() {
+  let requests = Arc::new(AtomicUsize::new(0));
+  let repository = CoalescingRestRepository::new(Arc::new(repository(
+    serve_workers(requests.clone()),
+    Duration::from_millis(0),
+  )));
+
+  let (first, second, missing) = tokio::join!(
+    repository.find_worker_by_id(1),
+    repository.find_worker_by_id(2),
+    repository.find_worker_by_id(3),
+  );
+
+  assert!(first.unwrap().is_some());
+  assert!(second.unwrap().is_some());
+  assert!(missing.unwrap().is_none());
+  assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_shares_the_loaded_index_instead_of_rebuilding_it() {
+  let requests = Arc::new(AtomicUsize::new(0));
+  let rest = Arc::new(repository(
+    serve_workers(requests.clone()),
+    Duration::from_secs(60),
+  ));
+  let repository = CoalescingRestRepository::new(rest.clone());
+
+  let index = repository.find_worker_index().await.unwrap();
+  assert!(Arc::ptr_eq(&index, &rest.loaded_worker_index().unwrap()));
+  assert!(repository.find_worker_by_id(2).await.unwrap().is_some());
+  assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
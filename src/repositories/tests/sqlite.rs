@@ -0,0 +1,158 @@
+use crate::domain::job::Job;
+use crate::domain::worker::Worker;
+use crate::dto::{JobDto, WorkerDto};
+use crate::fixtures::{job, worker};
+use crate::repositories::data_quality::{DataQualityService, DataQualityServiceImpl};
+use crate::repositories::rest::RestRepository;
+use crate::repositories::sqlite::SqliteRepository;
+use crate::repositories::store::RecordStore;
+use rusqlite::Connection;
+use serde_json::{json, Value};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+fn database_path(name: &str) -> PathBuf {
+  let path = std::env::temp_dir().join(format!(
+    "jobmatching-sqlite-{}-{}.db",
+    name,
+    std::process::id()
+  ));
+  let _ = std::fs::remove_file(&path);
+  path
+}
+
+fn open(path: &str) -> (SqliteRepository, Arc<DataQualityServiceImpl>) {
+  let data_quality = Arc::new(DataQualityServiceImpl::new());
+  (
+    SqliteRepository::open(path, data_quality.clone()).unwrap(),
+    data_quality,
+  )
+}
+
+fn full_worker() -> Worker {
+  worker(
+    1,
+    json!({
+      "certificates": ["Forklift", null], "skills": ["Cashier"], "maxCommuteMinutes": 45,
+      "availability": [
+        { "title": "Monday", "dayIndex": 1, "startTime": "22:00", "endTime": "06:00" },
+        { "title": "Friday", "dayIndex": 5 },
+      ],
+      "blackoutDates": [{ "startDate": "2021-06-01", "endDate": "2021-06-14" }],
+    }),
+  )
+}
+
+fn full_job() -> Job {
+  job(
+    7,
+    json!({
+      "requiredCertificates": ["Forklift"], "requiredSkills": ["Cashier"],
+      "preferredSkills": ["Barista"], "minimumRating": 3, "timezone": "Europe/Prague",
+      "schedule": {
+        "startDate": "2021-06-01", "endDate": "2021-06-30", "weekdays": [1, 5],
+        "shiftStart": "22:00", "shiftEnd": "06:00", "minimumCoverage": 0.5,
+      },
+    }),
+  )
+}
+
+fn worker_json(worker: &Worker) -> Value {
+  serde_json::to_value(WorkerDto::from(worker)).unwrap()
+}
+
+fn job_json(job: &Job) -> Value {
+  serde_json::to_value(JobDto::from(job)).unwrap()
+}
+
+#[test]
+fn test_applies_migrations_once() {
+  let path = database_path("migrations");
+  let path = path.to_str().unwrap();
+  open(path);
+  let version: i64 = Connection::open(path)
+    .unwrap()
+    .pragma_query_value(None, "user_version", |r| r.get(0))
+    .unwrap();
+  assert!(version > 0);
+
+  open(path);
+  let connection = Connection::open(path).unwrap();
+  let reopened_version: i64 = connection
+    .pragma_query_value(None, "user_version", |r| r.get(0))
+    .unwrap();
+  assert_eq!(reopened_version, version);
+  let blackout_table: i64 = connection
+    .query_row(
+      "SELECT COUNT(*) FROM sqlite_master WHERE name = 'worker_blackout_dates'",
+      rusqlite::NO_PARAMS,
+      |r| r.get(0),
+    )
+    .unwrap();
+  assert_eq!(blackout_table, 1);
+}
+
+#[tokio::test]
+async fn test_round_trips_imported_records() {
+  let (repository, _) = open(":memory:");
+  let (worker, job) = (full_worker(), full_job());
+  repository
+    .import(vec![worker.clone()], vec![job.clone()])
+    .await
+    .unwrap();
+
+  let workers = repository.find_all_workers().await.unwrap();
+  assert_eq!(workers.len(), 1);
+  assert_eq!(worker_json(&workers[0]), worker_json(&worker));
+  let found = repository.find_worker_by_id(1).await.unwrap().unwrap();
+  assert_eq!(worker_json(&found), worker_json(&worker));
+  assert!(repository.find_worker_by_id(2).await.unwrap().is_none());
+
+  let jobs = repository.find_all_jobs().await.unwrap();
+  assert_eq!(job_json(&jobs[0]), job_json(&job));
+  let found = repository.find_job_by_id(7).await.unwrap().unwrap();
+  assert_eq!(job_json(&found), job_json(&job));
+}
+
+#[tokio::test]
+async fn test_upserts_and_deletes_records() {
+  let (repository, _) = open(":memory:");
+  repository
+    .import(vec![full_worker()], vec![full_job()])
+    .await
+    .unwrap();
+
+  let updated = worker(1, json!({ "rating": 2, "skills": ["Barista"] }));
+  repository.upsert_worker(updated.clone()).await.unwrap();
+  let found = repository.find_worker_by_id(1).await.unwrap().unwrap();
+  assert_eq!(worker_json(&found), worker_json(&updated));
+
+  assert!(repository.delete_job(7).await.unwrap());
+  assert!(!repository.delete_job(7).await.unwrap());
+  assert!(repository.find_all_jobs().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_reports_stored_rows_that_fail_validation() {
+  let path = database_path("invalid-rows");
+  let path = path.to_str().unwrap();
+  let (repository, data_quality) = open(path);
+  repository
+    .import(vec![full_worker(), worker(2, json!({}))], vec![])
+    .await
+    .unwrap();
+  Connection::open(path)
+    .unwrap()
+    .execute(
+      "UPDATE workers SET max_job_distance = -1 WHERE user_id = 2",
+      rusqlite::NO_PARAMS,
+    )
+    .unwrap();
+
+  let workers = repository.find_all_workers().await.unwrap();
+  assert_eq!(workers.len(), 1);
+  let report = data_quality.get_report().remove("workers").unwrap();
+  assert_eq!((report.records, report.skipped), (2, 1));
+  assert_eq!(report.issues[0].id, Some(2));
+  assert_eq!(report.issues[0].kind, "invalid_field");
+}
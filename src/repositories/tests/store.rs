@@ -0,0 +1,44 @@
+use crate::domain::worker::Worker;
+use crate::fixtures::worker;
+use crate::repositories::index::IdIndex;
+use crate::repositories::store::PushedRecords;
+use serde_json::json;
+
+fn upstream() -> IdIndex<Worker> {
+  IdIndex::new(vec![worker(1, json!({})), worker(2, json!({}))])
+}
+
+fn ids(index: &IdIndex<Worker>) -> Vec<u32> {
+  index.to_vec().iter().map(|w| w.user_id).collect()
+}
+
+#[test]
+fn test_reapplies_pushes_over_snapshots_fetched_before_them() {
+  let pushed = PushedRecords::new();
+  let mut index = upstream();
+  let checkpoint = pushed.checkpoint();
+  pushed.upsert(&mut index, worker(3, json!({})));
+  assert!(pushed.remove(&mut index, 1));
+  assert_eq!(ids(&index), vec![2, 3]);
+
+  let mut refreshed = upstream();
+  pushed.apply(&mut refreshed, checkpoint);
+  assert_eq!(ids(&refreshed), vec![2, 3]);
+}
+
+#[test]
+fn test_drops_pushes_once_a_later_snapshot_is_stored() {
+  let pushed = PushedRecords::new();
+  let mut index = upstream();
+  pushed.upsert(&mut index, worker(3, json!({})));
+  assert!(pushed.remove(&mut index, 1));
+
+  let checkpoint = pushed.checkpoint();
+  let mut refreshed = upstream();
+  pushed.apply(&mut refreshed, checkpoint);
+  assert_eq!(ids(&refreshed), vec![1, 2]);
+
+  let mut refreshed = upstream();
+  pushed.apply(&mut refreshed, 0);
+  assert_eq!(ids(&refreshed), vec![1, 2]);
+}
@@ -0,0 +1,24 @@
+use crate::dto::CacheStatusDto;
+use crate::repositories::caching::CacheStatusService;
+use std::sync::Arc;
+use warp::filters::BoxedFilter;
+use warp::{Filter, Reply};
+
+pub fn route<CSS>(cache_status_service: Option<Arc<CSS>>) -> BoxedFilter<(impl Reply,)>
+where
+  CSS: CacheStatusService + Send + Sync + 'static,
+{
+  warp::path("cacheStatus")
+    .and(warp::get())
+    .map(move || match &cache_status_service {
+      Some(s) => warp::reply::json(&s.get_cache_status()),
+      None => warp::reply::json(&CacheStatusDto {
+        enabled: false,
+        ttl_seconds: 0,
+        max_staleness_seconds: 0,
+        workers: None,
+        jobs: None,
+      }),
+    })
+    .boxed()
+}
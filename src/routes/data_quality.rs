@@ -0,0 +1,14 @@
+use crate::repositories::data_quality::DataQualityService;
+use std::sync::Arc;
+use warp::filters::BoxedFilter;
+use warp::{Filter, Reply};
+
+pub fn route<DQS>(data_quality_service: Arc<DQS>) -> BoxedFilter<(impl Reply,)>
+where
+  DQS: DataQualityService + Send + Sync + 'static,
+{
+  warp::path("dataQuality")
+    .and(warp::get())
+    .map(move || warp::reply::json(&data_quality_service.get_report()))
+    .boxed()
+}
@@ -11,6 +11,14 @@ struct FindWorkersQuery {
   job_id: u32,
 }
 
+#[derive(Deserialize)]
+struct CountWorkersQuery {
+  #[serde(rename = "jobId")]
+  job_id: u32,
+  #[serde(rename = "withReasons", default)]
+  with_reasons: bool,
+}
+
 pub fn route<WMS, CS>(
   worker_match_service: Arc<WMS>,
   config_service: Arc<CS>,
@@ -52,14 +60,17 @@ where
 
   let count_workers = warp::path!("countWorkersforJob")
     .and(warp::get())
-    .and(warp::query().map(|q: FindWorkersQuery| q.job_id))
-    .and_then(move |job_id| {
+    .and(warp::query())
+    .and_then(move |q: CountWorkersQuery| {
       let wms_local = worker_match_service.clone();
       async move {
-        wms_local
-          .count_matching_workers(job_id)
-          .await
-          .map(|c| warp::reply::json(&c))
+        wms_local.count_matching_workers(q.job_id).await.map(|c| {
+          if q.with_reasons {
+            warp::reply::json(&c)
+          } else {
+            warp::reply::json(&c.count)
+          }
+        })
       }
     });
 
@@ -1,33 +1,53 @@
+mod cache_status;
 mod config;
+mod data_quality;
 mod find_jobs;
 mod find_workers;
 mod health;
+mod webhooks;
 
+#[cfg(test)]
+mod tests;
+
+use crate::errors::handler::handle_rejection;
+use crate::repositories::caching::CacheStatusService;
+use crate::repositories::data_quality::DataQualityService;
 use crate::services::config::ConfigService;
+use crate::services::ingestion::IngestionService;
 use crate::services::job_match::JobMatchService;
 use crate::services::rules::RulesService;
 use crate::services::worker_match::WorkerMatchService;
 use std::sync::Arc;
 use warp::Filter;
 
-pub fn route<RS, JMS, WMS, CS>(
+pub fn route<RS, JMS, WMS, CS, CSS, DQS, IS>(
   rules_service: Arc<RS>,
   job_match_service: Arc<JMS>,
   worker_match_service: Arc<WMS>,
   config_service: Arc<CS>,
+  cache_status_service: Option<Arc<CSS>>,
+  data_quality_service: Arc<DQS>,
+  ingestion_service: Option<Arc<IS>>,
 ) -> warp::filters::BoxedFilter<(impl warp::Reply,)>
 where
   RS: RulesService + Send + Sync + 'static,
   JMS: JobMatchService + Send + Sync + 'static,
   WMS: WorkerMatchService + Send + Sync + 'static,
   CS: ConfigService + Send + Sync + 'static,
+  CSS: CacheStatusService + Send + Sync + 'static,
+  DQS: DataQualityService + Send + Sync + 'static,
+  IS: IngestionService + Send + Sync + 'static,
 {
   warp::path!("api" / ..)
     .and(
       find_jobs::route(job_match_service.clone(), config_service.clone())
         .or(health::route(config_service.clone()))
         .or(config::route(rules_service))
+        .or(cache_status::route(cache_status_service))
+        .or(data_quality::route(data_quality_service))
+        .or(webhooks::route(ingestion_service, config_service.clone()))
         .or(find_workers::route(worker_match_service, config_service)),
     )
+    .recover(handle_rejection)
     .boxed()
 }
@@ -0,0 +1,38 @@
+use crate::fixtures::{job, worker, StubConfigService, StubRepository};
+use crate::routes::find_workers;
+use crate::services::rules::RulesServiceImpl;
+use crate::services::worker_match::WorkerMatchServiceImpl;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+async fn count_workers(query: &str) -> Value {
+  let repository = Arc::new(StubRepository::new(
+    vec![worker(1, json!({})), worker(2, json!({}))],
+    vec![job(7, json!({}))],
+  ));
+  let service = Arc::new(WorkerMatchServiceImpl::new(
+    Arc::new(RulesServiceImpl::new(Vec::new())),
+    repository,
+  ));
+  let filter = find_workers::route(service, Arc::new(StubConfigService::new(json!({}))));
+  let response = warp::test::request()
+    .path(&format!("/countWorkersforJob?{}", query))
+    .reply(&filter)
+    .await;
+
+  serde_json::from_slice(response.body()).unwrap()
+}
+
+#[tokio::test]
+async fn test_counts_workers_as_a_bare_number() {
+  assert_eq!(count_workers("jobId=7").await, json!(2));
+  assert_eq!(count_workers("jobId=7&withReasons=false").await, json!(2));
+}
+
+#[tokio::test]
+async fn test_includes_rejection_reasons_on_request() {
+  assert_eq!(
+    count_workers("jobId=7&withReasons=true").await,
+    json!({ "count": 2, "rejections": {} })
+  );
+}
@@ -0,0 +1,2 @@
+pub mod find_workers;
+pub mod webhooks;
@@ -0,0 +1,86 @@
+use crate::dto::{JobDto, WorkerDto};
+use crate::errors::handler::handle_rejection;
+use crate::fixtures::StubConfigService;
+use crate::routes::webhooks;
+use crate::services::ingestion::IngestionService;
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use warp::http::StatusCode;
+use warp::{Filter, Rejection};
+
+#[derive(Default)]
+struct StubIngestionService {
+  deleted_workers: Mutex<Vec<u32>>,
+}
+
+#[async_trait]
+impl IngestionService for StubIngestionService {
+  async fn upsert_worker(&self, _worker_id: u32, _worker: WorkerDto) -> Result<(), Rejection> {
+    Ok(())
+  }
+
+  async fn delete_worker(&self, worker_id: u32) -> Result<(), Rejection> {
+    self.deleted_workers.lock().unwrap().push(worker_id);
+    Ok(())
+  }
+
+  async fn upsert_job(&self, _job_id: u32, _job: JobDto) -> Result<(), Rejection> {
+    Ok(())
+  }
+
+  async fn delete_job(&self, _job_id: u32) -> Result<(), Rejection> {
+    Ok(())
+  }
+}
+
+fn config_service() -> Arc<StubConfigService> {
+  Arc::new(StubConfigService::new(
+    json!({ "webhook": { "token": "s3cret" } }),
+  ))
+}
+
+async fn delete_worker(
+  service: &Arc<StubIngestionService>,
+  authorization: Option<&str>,
+) -> StatusCode {
+  let filter = webhooks::route(Some(service.clone()), config_service()).recover(handle_rejection);
+  let mut request = warp::test::request().method("DELETE").path("/workers/4");
+  if let Some(authorization) = authorization {
+    request = request.header("authorization", authorization);
+  }
+
+  request.reply(&filter).await.status()
+}
+
+#[tokio::test]
+async fn test_rejects_requests_without_a_token() {
+  let service = Arc::new(StubIngestionService::default());
+  assert_eq!(
+    delete_worker(&service, None).await,
+    StatusCode::UNAUTHORIZED
+  );
+  assert!(service.deleted_workers.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_rejects_requests_with_a_wrong_token() {
+  let service = Arc::new(StubIngestionService::default());
+  for token in &["Bearer s3creT", "Bearer s3cret2", "Bearer", "s3cret"] {
+    assert_eq!(
+      delete_worker(&service, Some(token)).await,
+      StatusCode::UNAUTHORIZED
+    );
+  }
+  assert!(service.deleted_workers.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_accepts_requests_with_the_configured_token() {
+  let service = Arc::new(StubIngestionService::default());
+  assert_eq!(
+    delete_worker(&service, Some("Bearer s3cret")).await,
+    StatusCode::NO_CONTENT
+  );
+  assert_eq!(*service.deleted_workers.lock().unwrap(), vec![4]);
+}
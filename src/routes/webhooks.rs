@@ -0,0 +1,117 @@
+use crate::dto::{JobDto, WorkerDto};
+use crate::errors::unauthorized::UnauthorizedError;
+use crate::services::config::ConfigService;
+use crate::services::ingestion::IngestionService;
+use std::sync::Arc;
+use warp::filters::BoxedFilter;
+use warp::http::StatusCode;
+use warp::{Filter, Rejection, Reply};
+
+const MAX_BODY_BYTES: u64 = 64 * 1024;
+
+fn with_service<IS>(
+  ingestion_service: Option<Arc<IS>>,
+) -> impl Filter<Extract = (Arc<IS>,), Error = Rejection> + Clone
+where
+  IS: IngestionService + Send + Sync + 'static,
+{
+  warp::any().and_then(move || {
+    let service = ingestion_service.clone();
+    async move { service.ok_or_else(warp::reject::not_found) }
+  })
+}
+
+/// Compares every byte so the response time does not reveal how much of the
+/// token matched.
+fn tokens_match(header: &str, expected: &str) -> bool {
+  let (header, expected) = (header.as_bytes(), expected.as_bytes());
+  header.len() == expected.len()
+    && header
+      .iter()
+      .zip(expected)
+      .fold(0, |diff, (a, b)| diff | (a ^ b))
+      == 0
+}
+
+fn authorized<CS>(config_service: Arc<CS>) -> impl Filter<Extract = (), Error = Rejection> + Clone
+where
+  CS: ConfigService + Send + Sync + 'static,
+{
+  warp::header::optional::<String>("authorization")
+    .and_then(move |header: Option<String>| {
+      let expected = config_service
+        .get_config()
+        .webhook
+        .as_ref()
+        .map(|w| format!("Bearer {}", w.token));
+      async move {
+        match (header, expected) {
+          (Some(header), Some(expected)) if tokens_match(&header, &expected) => Ok(()),
+          _ => {
+            log::warn!("Rejecting unauthorized webhook request");
+            Err(warp::reject::custom(UnauthorizedError::new()))
+          }
+        }
+      }
+    })
+    .untuple_one()
+}
+
+fn no_content(_: ()) -> impl Reply {
+  warp::reply::with_status(warp::reply(), StatusCode::NO_CONTENT)
+}
+
+pub fn route<IS, CS>(
+  ingestion_service: Option<Arc<IS>>,
+  config_service: Arc<CS>,
+) -> BoxedFilter<(impl Reply,)>
+where
+  IS: IngestionService + Send + Sync + 'static,
+  CS: ConfigService + Send + Sync + 'static,
+{
+  let upsert = warp::post().or(warp::put()).unify();
+  let webhook = with_service(ingestion_service).and(authorized(config_service));
+
+  let upsert_worker = warp::path!("workers" / u32)
+    .and(upsert)
+    .and(webhook.clone())
+    .and(warp::body::content_length_limit(MAX_BODY_BYTES))
+    .and(warp::body::json())
+    .and_then(
+      |worker_id: u32, service: Arc<IS>, worker: WorkerDto| async move {
+        service
+          .upsert_worker(worker_id, worker)
+          .await
+          .map(no_content)
+      },
+    );
+
+  let delete_worker = warp::path!("workers" / u32)
+    .and(warp::delete())
+    .and(webhook.clone())
+    .and_then(|worker_id: u32, service: Arc<IS>| async move {
+      service.delete_worker(worker_id).await.map(no_content)
+    });
+
+  let upsert_job = warp::path!("jobs" / u32)
+    .and(upsert)
+    .and(webhook.clone())
+    .and(warp::body::content_length_limit(MAX_BODY_BYTES))
+    .and(warp::body::json())
+    .and_then(|job_id: u32, service: Arc<IS>, job: JobDto| async move {
+      service.upsert_job(job_id, job).await.map(no_content)
+    });
+
+  let delete_job = warp::path!("jobs" / u32)
+    .and(warp::delete())
+    .and(webhook)
+    .and_then(|job_id: u32, service: Arc<IS>| async move {
+      service.delete_job(job_id).await.map(no_content)
+    });
+
+  upsert_worker
+    .or(delete_worker)
+    .or(upsert_job)
+    .or(delete_job)
+    .boxed()
+}
@@ -30,7 +30,7 @@ impl FileConfigService {
 impl ConfigService for FileConfigService {
   fn get_config(&self) -> &Config {
     match &self.config {
-      Some(c) => &c,
+      Some(c) => c,
       None => {
         panic!("Attempted to access config, but config has not been loaded from file");
       }
@@ -0,0 +1,105 @@
+use crate::domain::job::Job;
+use crate::domain::worker::Worker;
+use crate::dto::{JobDto, WorkerDto};
+use crate::errors::bad_request::BadRequestError;
+use crate::errors::not_found::NotFoundError;
+use crate::repositories::store::RecordStore;
+use async_trait::async_trait;
+use std::convert::TryFrom;
+use std::sync::Arc;
+use warp::reject::Rejection;
+
+#[async_trait]
+pub trait IngestionService {
+  async fn upsert_worker(&self, worker_id: u32, worker: WorkerDto) -> Result<(), Rejection>;
+  async fn delete_worker(&self, worker_id: u32) -> Result<(), Rejection>;
+  async fn upsert_job(&self, job_id: u32, job: JobDto) -> Result<(), Rejection>;
+  async fn delete_job(&self, job_id: u32) -> Result<(), Rejection>;
+}
+
+pub struct IngestionServiceImpl {
+  stores: Vec<Arc<dyn RecordStore + Send + Sync>>,
+}
+
+impl IngestionServiceImpl {
+  pub fn new(stores: Vec<Arc<dyn RecordStore + Send + Sync>>) -> IngestionServiceImpl {
+    IngestionServiceImpl { stores }
+  }
+}
+
+#[async_trait]
+impl IngestionService for IngestionServiceImpl {
+  async fn upsert_worker(&self, worker_id: u32, worker: WorkerDto) -> Result<(), Rejection> {
+    if worker.user_id != worker_id {
+      log::warn!(
+        "Worker body has userId {} but was pushed to worker {}",
+        worker.user_id,
+        worker_id
+      );
+      return Err(warp::reject::custom(BadRequestError::new()));
+    }
+    let worker = Worker::try_from(worker).map_err(|problems| {
+      log::warn!(
+        "Rejecting pushed worker {}: {}",
+        worker_id,
+        problems.join("; ")
+      );
+      warp::reject::custom(BadRequestError::new())
+    })?;
+
+    for store in &self.stores {
+      store.upsert_worker(worker.clone()).await?;
+    }
+    log::info!("Upserted worker {}", worker_id);
+    Ok(())
+  }
+
+  async fn delete_worker(&self, worker_id: u32) -> Result<(), Rejection> {
+    let mut found = false;
+    for store in &self.stores {
+      found |= store.delete_worker(worker_id).await?;
+    }
+    if !found {
+      log::warn!("Could not delete unknown worker {}", worker_id);
+      return Err(warp::reject::custom(NotFoundError::new()));
+    }
+
+    log::info!("Deleted worker {}", worker_id);
+    Ok(())
+  }
+
+  async fn upsert_job(&self, job_id: u32, job: JobDto) -> Result<(), Rejection> {
+    if job.job_id != job_id {
+      log::warn!(
+        "Job body has jobId {} but was pushed to job {}",
+        job.job_id,
+        job_id
+      );
+      return Err(warp::reject::custom(BadRequestError::new()));
+    }
+    let job = Job::try_from(job).map_err(|problems| {
+      log::warn!("Rejecting pushed job {}: {}", job_id, problems.join("; "));
+      warp::reject::custom(BadRequestError::new())
+    })?;
+
+    for store in &self.stores {
+      store.upsert_job(job.clone()).await?;
+    }
+    log::info!("Upserted job {}", job_id);
+    Ok(())
+  }
+
+  async fn delete_job(&self, job_id: u32) -> Result<(), Rejection> {
+    let mut found = false;
+    for store in &self.stores {
+      found |= store.delete_job(job_id).await?;
+    }
+    if !found {
+      log::warn!("Could not delete unknown job {}", job_id);
+      return Err(warp::reject::custom(NotFoundError::new()));
+    }
+
+    log::info!("Deleted job {}", job_id);
+    Ok(())
+  }
+}
@@ -1,5 +1,7 @@
 use super::rules::{MatchScore, RulesService};
-use crate::dto::{JobDto, MatchScoreDto, StackDiagnosisResponse, WorkerDto};
+use crate::domain::job::Job;
+use crate::domain::worker::Worker;
+use crate::dto::{JobDto, MatchScoreDto, StackDiagnosisResponse};
 use crate::engine::config::{EvaluationConfig, EvaluationContext};
 use crate::errors::bad_request::BadRequestError;
 use crate::repositories::rest::RestRepository;
@@ -43,13 +45,14 @@ impl JobMatchServiceImpl {
     }
   }
 
-  async fn load_data(&self, worker_id: u32) -> Result<(WorkerDto, Vec<JobDto>), Rejection> {
+  async fn load_data(&self, worker_id: u32) -> Result<(Worker, Vec<Job>, bool), Rejection> {
     let (worker, jobs) = tokio::join!(
-      self.rest_repository.find_worker_by_id(worker_id),
-      self.rest_repository.find_all_jobs()
+      self.rest_repository.fetch_worker_by_id(worker_id),
+      self.rest_repository.fetch_all_jobs()
     );
-    match worker? {
-      Some(w) => Ok((w, jobs?)),
+    let (worker, jobs) = (worker?, jobs?);
+    match worker.value {
+      Some(w) => Ok((w, jobs.value, worker.stale || jobs.stale)),
       None => {
         log::warn!("Could not find worker {}", worker_id);
         Err(warp::reject::custom(BadRequestError::new()))
@@ -59,11 +62,11 @@ impl JobMatchServiceImpl {
 
   fn score_jobs<'a>(
     &self,
-    worker: &WorkerDto,
-    jobs: &'a Vec<JobDto>,
+    worker: &Worker,
+    jobs: &'a [Job],
     job_limit: u32,
     config: &EvaluationConfig,
-  ) -> Vec<(&'a JobDto, MatchScore)> {
+  ) -> Vec<(&'a Job, MatchScore)> {
     log::debug!("Calculating jobs for Worker {}", worker.user_id);
     self
       .rules_service
@@ -71,7 +74,7 @@ impl JobMatchServiceImpl {
         &jobs
           .iter()
           .map(|j| EvaluationContext::new(worker, j, config))
-          .collect(),
+          .collect::<Vec<EvaluationContext>>(),
         job_limit,
       )
       .into_iter()
@@ -88,7 +91,7 @@ impl JobMatchService for JobMatchServiceImpl {
     job_limit: u32,
   ) -> Result<StackDiagnosisResponse, Rejection> {
     let start = Instant::now();
-    let (worker, jobs) = self.load_data(worker_id).await?;
+    let (worker, jobs, stale) = self.load_data(worker_id).await?;
 
     let config = EvaluationConfig {
       with_diagnosis: true,
@@ -101,6 +104,7 @@ impl JobMatchService for JobMatchServiceImpl {
         worker_id,
         job_id: j.0.job_id,
         rating: j.1.rating,
+        rejections: j.1.rejections,
         rule_results: j.1.details,
       })
       .collect();
@@ -110,6 +114,7 @@ impl JobMatchService for JobMatchServiceImpl {
     Ok(StackDiagnosisResponse {
       jobs,
       calculation_time_ms,
+      stale_data: stale,
     })
   }
 
@@ -124,11 +129,11 @@ impl JobMatchService for JobMatchServiceImpl {
     );
     let worker = worker?;
     let job = job?;
-    if let None = worker {
+    if worker.is_none() {
       log::warn!("Could not find Worker {}", worker_id);
       return Err(warp::reject::custom(BadRequestError::new()));
     }
-    if let None = job {
+    if job.is_none() {
       log::warn!("Could not find Job {}", job_id);
       return Err(warp::reject::custom(BadRequestError::new()));
     }
@@ -147,6 +152,7 @@ impl JobMatchService for JobMatchServiceImpl {
       worker_id,
       job_id,
       rating: result.rating,
+      rejections: result.rejections,
       rule_results: result.details,
     })
   }
@@ -157,7 +163,7 @@ impl JobMatchService for JobMatchServiceImpl {
     job_limit: u32,
   ) -> Result<Vec<JobDto>, Rejection> {
     let start = Instant::now();
-    let (worker, jobs) = self.load_data(worker_id).await?;
+    let (worker, jobs, _) = self.load_data(worker_id).await?;
     let config = EvaluationConfig {
       with_diagnosis: false,
       short_circuit_failures: true,
@@ -165,7 +171,7 @@ impl JobMatchService for JobMatchServiceImpl {
     let jobs = self
       .score_jobs(&worker, &jobs, job_limit, &config)
       .iter()
-      .map(|j| j.0.clone())
+      .map(|j| JobDto::from(j.0))
       .collect();
     let calculation_time_ms = start.elapsed().as_millis();
     log::debug!("Job stack calculated in {}ms", calculation_time_ms);
@@ -1,4 +1,8 @@
 pub mod config;
+pub mod ingestion;
 pub mod job_match;
 pub mod rules;
 pub mod worker_match;
+
+#[cfg(test)]
+mod tests;
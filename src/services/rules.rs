@@ -1,11 +1,26 @@
 use crate::collections::CappedHeap;
-use crate::dto::{RuleConfigDto, RuleResultDto};
+use crate::dto::{RejectionDto, RuleConfigDto, RuleResultDto};
 use crate::engine::config::EvaluationContext;
-use crate::engine::match_rating::MatchRating;
+use crate::engine::match_rating::{MatchRating, RatingOutcome};
+use std::collections::HashMap;
 
 pub struct MatchScore {
   pub rating: f64,
   pub details: Vec<RuleResultDto>,
+  pub rejections: Vec<RejectionDto>,
+}
+
+impl MatchScore {
+  pub fn is_rejected(&self) -> bool {
+    !self.rejections.is_empty()
+  }
+}
+
+pub struct MatchCount {
+  pub satisfied: usize,
+  /// Rejected entries by the code of their first rejection only, so the
+  /// values add up to the number of rejected entries.
+  pub rejections: HashMap<String, usize>,
 }
 
 pub trait RulesService {
@@ -13,10 +28,10 @@ pub trait RulesService {
   fn score_job_for_worker(&self, ctx: &EvaluationContext) -> MatchScore;
   fn score_entries<'a, 'b, 'c, 'd>(
     &self,
-    ctxs: &'a Vec<EvaluationContext<'b, 'c, 'd>>,
+    ctxs: &'a [EvaluationContext<'b, 'c, 'd>],
     limit: u32,
   ) -> Vec<(&'a EvaluationContext<'b, 'c, 'd>, MatchScore)>;
-  fn count_satisfied(&self, ctxs: &Vec<EvaluationContext>) -> usize;
+  fn count_satisfied(&self, ctxs: &[EvaluationContext]) -> MatchCount;
 }
 
 pub struct RulesServiceImpl {
@@ -50,23 +65,43 @@ impl RulesService for RulesServiceImpl {
     let mut score = MatchScore {
       rating: 0.0,
       details: Vec::new(),
+      rejections: Vec::new(),
     };
     for match_rating in &self.match_ratings {
-      let result = match_rating.determine_rating(&ctx);
+      let result = match_rating.determine_rating(ctx);
+      let rule_score = result.outcome.score().max(0.0).min(1.0);
+      let rating = match_rating.get_weight() * rule_score;
+      let rule_name = String::from(match_rating.get_name());
+      let (outcome, rejection) = match &result.outcome {
+        RatingOutcome::Pass(_) => ("PASS", None),
+        RatingOutcome::NotApplicable => ("NOT_APPLICABLE", None),
+        RatingOutcome::Reject(reason) => (
+          "REJECT",
+          Some(RejectionDto {
+            rule_name: rule_name.clone(),
+            code: String::from(reason.code()),
+            message: reason.to_string(),
+          }),
+        ),
+      };
       score.details.push(RuleResultDto {
-        rule_name: String::from(match_rating.get_name()),
+        rule_name,
         weight: match_rating.get_weight(),
-        rating: result.rating,
+        outcome: String::from(outcome),
+        score: rule_score,
+        rating,
+        rejection: rejection.clone(),
         metrics: result.metrics,
       });
-      if result.rating < 0.0 || score.rating < 0.0 {
+      if let Some(rejection) = rejection {
+        score.rejections.push(rejection);
         score.rating = -1.0;
         if ctx.config.short_circuit_failures {
           log::debug!("Short-circuit falure; not running further rules");
           break;
         }
-      } else {
-        score.rating += result.rating;
+      } else if !score.is_rejected() {
+        score.rating += rating;
       }
     }
 
@@ -76,7 +111,7 @@ impl RulesService for RulesServiceImpl {
 
   fn score_entries<'a, 'b, 'c, 'd>(
     &self,
-    ctxs: &'a Vec<EvaluationContext<'b, 'c, 'd>>,
+    ctxs: &'a [EvaluationContext<'b, 'c, 'd>],
     limit: u32,
   ) -> Vec<(&'a EvaluationContext<'b, 'c, 'd>, MatchScore)> {
     log::debug!("Scoring & ranking matches");
@@ -84,32 +119,35 @@ impl RulesService for RulesServiceImpl {
     let mut result_heap: CappedHeap<(&EvaluationContext, MatchScore)> =
       CappedHeap::new(limit as usize);
     for ctx in ctxs {
-      let result = self.score_job_for_worker(&ctx);
-      if result.rating >= 0.0 {
+      let result = self.score_job_for_worker(ctx);
+      if !result.is_rejected() {
         result_heap.push(result.rating, (ctx, result));
       }
     }
 
     let mut results: Vec<(&EvaluationContext, MatchScore)> = Vec::new();
-    loop {
-      match result_heap.pop() {
-        Some(n) => results.push(n),
-        None => break,
-      }
+    while let Some(n) = result_heap.pop() {
+      results.push(n);
     }
     results.reverse();
 
     results
   }
 
-  fn count_satisfied(&self, ctxs: &Vec<EvaluationContext>) -> usize {
+  fn count_satisfied(&self, ctxs: &[EvaluationContext]) -> MatchCount {
     log::debug!("Counting matching entries");
 
-    ctxs
-      .iter()
-      .map(|c| self.score_job_for_worker(c))
-      .map(|m| m.rating)
-      .filter(|r| *r >= 0.0)
-      .count()
+    let mut count = MatchCount {
+      satisfied: 0,
+      rejections: HashMap::new(),
+    };
+    for score in ctxs.iter().map(|c| self.score_job_for_worker(c)) {
+      match score.rejections.first() {
+        Some(rejection) => *count.rejections.entry(rejection.code.clone()).or_insert(0) += 1,
+        None => count.satisfied += 1,
+      }
+    }
+
+    count
   }
 }
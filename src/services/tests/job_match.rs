@@ -0,0 +1,29 @@
+use crate::domain::job::Job;
+use crate::fixtures::{job_dto, worker, StubRepository};
+use crate::services::job_match::{JobMatchService, JobMatchServiceImpl};
+use crate::services::rules::RulesServiceImpl;
+use serde_json::json;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_returns_upstream_jobs_unchanged() {
+  let upstream = job_dto(
+    7,
+    json!({
+      "location": { "latitude": "49.700", "longitude": "north" },
+      "billRate": "22.50 EUR",
+      "requiredCertificates": [" Forklift ", "forklift"],
+      "requiredSkills": ["Cashier", "cashier"],
+    }),
+  );
+  let (job, warnings) = Job::parse(upstream.clone()).unwrap();
+  assert_eq!(warnings.len(), 2);
+  let repository = Arc::new(StubRepository::new(vec![worker(1, json!({}))], vec![job]));
+  let service = JobMatchServiceImpl::new(Arc::new(RulesServiceImpl::new(Vec::new())), repository);
+
+  let jobs = service.find_best_jobs_for_worker(1, 10).await.unwrap();
+  assert_eq!(
+    serde_json::to_value(jobs).unwrap(),
+    json!([serde_json::to_value(&upstream).unwrap()])
+  );
+}
@@ -0,0 +1,3 @@
+pub mod job_match;
+pub mod rules;
+pub mod worker_match;
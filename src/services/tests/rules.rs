@@ -0,0 +1,115 @@
+use crate::engine::config::{EvaluationConfig, EvaluationContext};
+use crate::engine::match_rating::{MatchRating, RatingOutcome, RatingResult, RejectionReason};
+use crate::fixtures::{job, worker};
+use crate::services::rules::{RulesService, RulesServiceImpl};
+use serde_json::json;
+use std::collections::HashMap;
+
+struct StubRule {
+  weight: f64,
+  outcome: fn(u32) -> RatingOutcome,
+}
+
+impl MatchRating for StubRule {
+  fn get_name(&self) -> &str {
+    "Stub"
+  }
+
+  fn get_weight(&self) -> f64 {
+    self.weight
+  }
+
+  fn determine_rating(&self, ctx: &EvaluationContext) -> RatingResult {
+    RatingResult {
+      outcome: (self.outcome)(ctx.worker.user_id),
+      metrics: HashMap::new(),
+    }
+  }
+}
+
+fn service(rules: Vec<StubRule>) -> RulesServiceImpl {
+  RulesServiceImpl::new(
+    rules
+      .into_iter()
+      .map(|r| Box::new(r) as Box<dyn MatchRating + Send + Sync>)
+      .collect(),
+  )
+}
+
+fn rejects_licence(worker_id: u32) -> RatingOutcome {
+  match worker_id {
+    1 | 2 => RatingOutcome::Reject(RejectionReason::DriversLicenseRequired),
+    _ => RatingOutcome::Pass(1.0),
+  }
+}
+
+fn rejects_rating(worker_id: u32) -> RatingOutcome {
+  match worker_id {
+    2 | 3 => RatingOutcome::Reject(RejectionReason::RatingBelowMinimum {
+      rating: 1,
+      minimum_rating: 3,
+    }),
+    _ => RatingOutcome::Pass(1.0),
+  }
+}
+
+#[test]
+fn test_counts_each_rejected_entry_under_its_first_rejection() {
+  let service = service(vec![
+    StubRule {
+      weight: 1.0,
+      outcome: rejects_licence,
+    },
+    StubRule {
+      weight: 1.0,
+      outcome: rejects_rating,
+    },
+  ]);
+  let job = job(7, json!({}));
+  let workers: Vec<_> = (1..=5).map(|id| worker(id, json!({}))).collect();
+
+  for short_circuit_failures in &[true, false] {
+    let config = EvaluationConfig {
+      with_diagnosis: false,
+      short_circuit_failures: *short_circuit_failures,
+    };
+    let ctxs: Vec<EvaluationContext> = workers
+      .iter()
+      .map(|w| EvaluationContext::new(w, &job, &config))
+      .collect();
+    let count = service.count_satisfied(&ctxs);
+
+    assert_eq!(count.satisfied, 2);
+    assert_eq!(count.rejections.len(), 2);
+    assert_eq!(count.rejections["DRIVERS_LICENSE_REQUIRED"], 2);
+    assert_eq!(count.rejections["RATING_BELOW_MINIMUM"], 1);
+  }
+}
+
+#[test]
+fn test_clamps_rule_scores_before_weighting() {
+  let service = service(vec![
+    StubRule {
+      weight: 3.0,
+      outcome: |_| RatingOutcome::Pass(1.7),
+    },
+    StubRule {
+      weight: 2.0,
+      outcome: |_| RatingOutcome::Pass(-0.5),
+    },
+    StubRule {
+      weight: 4.0,
+      outcome: |_| RatingOutcome::Pass(0.25),
+    },
+  ]);
+  let (worker, job) = (worker(1, json!({})), job(7, json!({})));
+  let config = EvaluationConfig {
+    with_diagnosis: true,
+    short_circuit_failures: false,
+  };
+  let score = service.score_job_for_worker(&EvaluationContext::new(&worker, &job, &config));
+
+  let scores: Vec<(f64, f64)> = score.details.iter().map(|d| (d.score, d.rating)).collect();
+  assert_eq!(scores, vec![(1.0, 3.0), (0.0, 0.0), (0.25, 1.0)]);
+  assert_eq!(score.rating, 4.0);
+}
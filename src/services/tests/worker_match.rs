@@ -0,0 +1,67 @@
+use crate::domain::worker::Worker;
+use crate::fixtures::{job, worker, worker_dto, StubRepository};
+use crate::services::rules::RulesServiceImpl;
+use crate::services::worker_match::{WorkerMatchService, WorkerMatchServiceImpl};
+use serde_json::json;
+use std::sync::Arc;
+
+fn service() -> WorkerMatchServiceImpl {
+  let repository = Arc::new(StubRepository::new(
+    vec![
+      worker(1, json!({})),
+      worker(2, json!({ "isActive": false })),
+      worker(3, json!({})),
+    ],
+    vec![job(7, json!({}))],
+  ));
+  WorkerMatchServiceImpl::new(Arc::new(RulesServiceImpl::new(Vec::new())), repository)
+}
+
+#[tokio::test]
+async fn test_excludes_inactive_workers_from_matches() {
+  let service = service();
+  let mut worker_ids: Vec<u32> = service
+    .find_best_workers_for_job(7, 10)
+    .await
+    .unwrap()
+    .iter()
+    .map(|w| w.user_id)
+    .collect();
+  worker_ids.sort_unstable();
+  assert_eq!(worker_ids, vec![1, 3]);
+
+  let diagnosis = service.rate_workers_for_job(7, 10).await.unwrap();
+  assert_eq!(diagnosis.workers.len(), 2);
+  assert_eq!(diagnosis.excluded.len(), 1);
+  assert_eq!(diagnosis.excluded[0].worker_id, 2);
+}
+
+#[tokio::test]
+async fn test_excludes_inactive_workers_from_the_count() {
+  let count = service().count_matching_workers(7).await.unwrap();
+  assert_eq!(count.count, 2);
+}
+
+#[tokio::test]
+async fn test_returns_upstream_workers_unchanged() {
+  let upstream = worker_dto(
+    1,
+    json!({
+      "certificates": [" Forklift ", "forklift", null],
+      "availability": [null, { "title": "Someday", "dayIndex": 9 }],
+      "transportation": "car",
+      "jobSearchAddress": { "latitude": "49.780", "longitude": "east", "maxJobDistance": 30.5, "unit": "km" },
+    }),
+  );
+  let (worker, warnings) = Worker::parse(upstream.clone()).unwrap();
+  assert!(!warnings.is_empty());
+  let repository = Arc::new(StubRepository::new(vec![worker], vec![job(7, json!({}))]));
+  let service =
+    WorkerMatchServiceImpl::new(Arc::new(RulesServiceImpl::new(Vec::new())), repository);
+
+  let workers = service.find_best_workers_for_job(7, 10).await.unwrap();
+  assert_eq!(
+    serde_json::to_value(workers).unwrap(),
+    json!([serde_json::to_value(&upstream).unwrap()])
+  );
+}
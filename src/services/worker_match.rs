@@ -1,5 +1,9 @@
 use super::rules::{MatchScore, RulesService};
-use crate::dto::{JobDto, MatchScoreDto, WorkerDto, WorkersDiagnosisResponse};
+use crate::domain::job::Job;
+use crate::domain::worker::Worker;
+use crate::dto::{
+  ExcludedWorkerDto, MatchScoreDto, WorkerCountResponse, WorkerDto, WorkersDiagnosisResponse,
+};
 use crate::engine::config::{EvaluationConfig, EvaluationContext};
 use crate::errors::bad_request::BadRequestError;
 use crate::repositories::rest::RestRepository;
@@ -20,7 +24,7 @@ pub trait WorkerMatchService {
     job_id: u32,
     worker_limit: u32,
   ) -> Result<Vec<WorkerDto>, Rejection>;
-  async fn count_matching_workers(&self, job_id: u32) -> Result<usize, Rejection>;
+  async fn count_matching_workers(&self, job_id: u32) -> Result<WorkerCountResponse, Rejection>;
 }
 
 pub struct WorkerMatchServiceImpl {
@@ -39,13 +43,30 @@ impl WorkerMatchServiceImpl {
     }
   }
 
-  async fn load_data(&self, job_id: u32) -> Result<(JobDto, Vec<WorkerDto>), Rejection> {
+  async fn load_data(
+    &self,
+    job_id: u32,
+  ) -> Result<(Job, Vec<Worker>, Vec<ExcludedWorkerDto>, bool), Rejection> {
     let (job, workers) = tokio::join!(
-      self.rest_repository.find_job_by_id(job_id),
-      self.rest_repository.find_all_workers(),
+      self.rest_repository.fetch_job_by_id(job_id),
+      self.rest_repository.fetch_all_workers(),
     );
-    match job? {
-      Some(j) => Ok((j, workers?)),
+    let (job, workers) = (job?, workers?);
+    let stale = job.stale || workers.stale;
+    match job.value {
+      Some(j) => {
+        let (workers, inactive): (Vec<Worker>, Vec<Worker>) =
+          workers.value.into_iter().partition(|w| w.active);
+        log::debug!("Excluding {} inactive workers", inactive.len());
+        let excluded = inactive
+          .iter()
+          .map(|w| ExcludedWorkerDto {
+            worker_id: w.user_id,
+            reason: String::from("Worker is not active"),
+          })
+          .collect();
+        Ok((j, workers, excluded, stale))
+      }
       None => {
         log::warn!("Could not find job {}", job_id);
         Err(warp::reject::custom(BadRequestError::new()))
@@ -55,11 +76,11 @@ impl WorkerMatchServiceImpl {
 
   fn score_workers<'a>(
     &self,
-    job: &JobDto,
-    workers: &'a Vec<WorkerDto>,
+    job: &Job,
+    workers: &'a [Worker],
     worker_limit: u32,
     config: &EvaluationConfig,
-  ) -> Vec<(&'a WorkerDto, MatchScore)> {
+  ) -> Vec<(&'a Worker, MatchScore)> {
     log::debug!("Calculating workers for Job {}", job.job_id);
     self
       .rules_service
@@ -67,7 +88,7 @@ impl WorkerMatchServiceImpl {
         &workers
           .iter()
           .map(|w| EvaluationContext::new(w, job, config))
-          .collect(),
+          .collect::<Vec<EvaluationContext>>(),
         worker_limit,
       )
       .into_iter()
@@ -84,7 +105,7 @@ impl WorkerMatchService for WorkerMatchServiceImpl {
     worker_limit: u32,
   ) -> Result<WorkersDiagnosisResponse, Rejection> {
     let start = Instant::now();
-    let (job, workers) = self.load_data(job_id).await?;
+    let (job, workers, excluded, stale) = self.load_data(job_id).await?;
 
     let config = EvaluationConfig {
       with_diagnosis: true,
@@ -97,6 +118,7 @@ impl WorkerMatchService for WorkerMatchServiceImpl {
         worker_id: w.0.user_id,
         job_id,
         rating: w.1.rating,
+        rejections: w.1.rejections,
         rule_results: w.1.details,
       })
       .collect();
@@ -105,7 +127,9 @@ impl WorkerMatchService for WorkerMatchServiceImpl {
 
     Ok(WorkersDiagnosisResponse {
       workers,
+      excluded,
       calculation_time_ms,
+      stale_data: stale,
     })
   }
 
@@ -115,7 +139,7 @@ impl WorkerMatchService for WorkerMatchServiceImpl {
     worker_limit: u32,
   ) -> Result<Vec<WorkerDto>, Rejection> {
     let start = Instant::now();
-    let (job, workers) = self.load_data(job_id).await?;
+    let (job, workers, _, _) = self.load_data(job_id).await?;
     let config = EvaluationConfig {
       with_diagnosis: false,
       short_circuit_failures: true,
@@ -123,7 +147,7 @@ impl WorkerMatchService for WorkerMatchServiceImpl {
     let workers = self
       .score_workers(&job, &workers, worker_limit, &config)
       .iter()
-      .map(|w| w.0.clone())
+      .map(|w| WorkerDto::from(w.0))
       .collect();
     let calculation_time_ms = start.elapsed().as_millis();
     log::debug!("Matching workers calculated in {}ms", calculation_time_ms);
@@ -131,9 +155,9 @@ impl WorkerMatchService for WorkerMatchServiceImpl {
     Ok(workers)
   }
 
-  async fn count_matching_workers(&self, job_id: u32) -> Result<usize, Rejection> {
+  async fn count_matching_workers(&self, job_id: u32) -> Result<WorkerCountResponse, Rejection> {
     let start = Instant::now();
-    let (job, workers) = self.load_data(job_id).await?;
+    let (job, workers, _, _) = self.load_data(job_id).await?;
     let config = EvaluationConfig {
       with_diagnosis: false,
       short_circuit_failures: true,
@@ -142,11 +166,14 @@ impl WorkerMatchService for WorkerMatchServiceImpl {
       &workers
         .iter()
         .map(|w| EvaluationContext::new(w, &job, &config))
-        .collect(),
+        .collect::<Vec<EvaluationContext>>(),
     );
     let calculation_time_ms = start.elapsed().as_millis();
     log::debug!("Matching workers counted in {}ms", calculation_time_ms);
 
-    Ok(count)
+    Ok(WorkerCountResponse {
+      count: count.satisfied,
+      rejections: count.rejections,
+    })
   }
 }